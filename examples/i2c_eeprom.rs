@@ -41,10 +41,9 @@ fn main() -> ! {
     //
     // See the usart example for a detailed explanation on how the usart setup works
     let clock_config = {
-        syscon.uartfrg.set_clkdiv(6);
-        syscon.uartfrg.set_frgmult(22);
-        syscon.uartfrg.set_frgdiv(0xff);
-        UsartClock::new(&syscon.uartfrg, 0, 16)
+        let u_pclk = syscon.uartfrg.set_baud_rates(12_000_000, &[115200]);
+        let (clock_config, _) = UsartClock::from_u_pclk(&u_pclk, 115200);
+        clock_config
     };
     #[cfg(feature = "845")]
     // Set baud rate to 115200 baud
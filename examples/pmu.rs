@@ -24,10 +24,8 @@ fn main() -> ! {
     let mut syscon = p.SYSCON.split();
 
     // 115200 baud
-    syscon.uartfrg.set_clkdiv(6);
-    syscon.uartfrg.set_frgmult(22);
-    syscon.uartfrg.set_frgdiv(0xff);
-    let clock_config = UsartClock::new(&syscon.uartfrg, 0, 16);
+    let u_pclk = syscon.uartfrg.set_baud_rates(12_000_000, &[115200]);
+    let (clock_config, _) = UsartClock::from_u_pclk(&u_pclk, 115200);
 
     let (u0_rxd, _) = swm
         .movable_functions
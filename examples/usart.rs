@@ -23,33 +23,18 @@ fn main() -> ! {
     #[cfg(feature = "82x")]
     // Set baud rate to 115200 baud
     //
-    // The common peripheral clock for all UART units, U_PCLK, needs to be set
-    // to 16 times the desired baud rate. This results in a frequency of
-    // 1843200 Hz for U_PLCK.
-    //
-    // We assume the main clock runs at 12 Mhz. To get close to the desired
-    // frequency for U_PLCK, we divide that by 6 using UARTCLKDIV, resulting in
-    // a frequency of 2 Mhz.
-    //
-    // To get to the desired 1843200 Hz, we need to further divide the frequency
-    // using the fractional baud rate generator. The fractional baud rate
-    // generator divides the frequency by `1 + MULT/DIV`.
-    //
-    // DIV must always be 256. To achieve this, we need to set the UARTFRGDIV to
-    // 0xff. MULT can then be fine-tuned to get as close as possible to the
-    // desired value. We choose the value 22, which we write into UARTFRGMULT.
-    //
-    // Finally, we can set an additional divider value for the UART unit by
-    // passing it as an argument to `BaudRate::new` (this will set the BRG
-    // register). As we are already close enough to the desired value, we pass
-    // 0, resulting in no further division.
+    // The common peripheral clock for all UART units, U_PCLK, is derived from
+    // the main clock, which we assume runs at 12 MHz. `set_baud_rates`
+    // configures the UART clock divider and fractional baud rate generator,
+    // such that the baud rates we pass can be reached with the lowest possible
+    // error. From the resulting U_PCLK, we can then create the clock
+    // configuration for USART0.
     //
     // All of this is somewhat explained in the user manual, section 13.3.1.
     let clock_config = {
-        syscon.uartfrg.set_clkdiv(6);
-        syscon.uartfrg.set_frgmult(22);
-        syscon.uartfrg.set_frgdiv(0xff);
-        UsartClock::new(&syscon.uartfrg, 0, 16)
+        let u_pclk = syscon.uartfrg.set_baud_rates(12_000_000, &[115200]);
+        let (clock_config, _error) = UsartClock::from_u_pclk(&u_pclk, 115200);
+        clock_config
     };
 
     #[cfg(feature = "845")]
@@ -1,20 +1,78 @@
 //! API for Direct Memory Access (DMA)
 //!
 //! The DMA controller is described in the user manual, chapter 12.
-
+//!
+//! # Channels and Peripherals
+//!
+//! Each DMA channel is hard-wired to the DMA request of one peripheral. A
+//! peripheral source or destination can only be used with its channel, which
+//! is checked at compile-time (see [`CompatibleWith`]).
+//!
+//! | Channel | LPC82x       | LPC845       |
+//! | ------- | ------------ | ------------ |
+//! | 0       | USART0 RX    | USART0 RX    |
+//! | 1       | USART0 TX    | USART0 TX    |
+//! | 2       | USART1 RX    | USART1 RX    |
+//! | 3       | USART1 TX    | USART1 TX    |
+//! | 4       | USART2 RX    | USART2 RX    |
+//! | 5       | USART2 TX    | USART2 TX    |
+//! | 6       | SPI0 RX      | USART3 RX    |
+//! | 7       | SPI0 TX      | USART3 TX    |
+//! | 8       | SPI1 RX      | USART4 RX    |
+//! | 9       | SPI1 TX      | USART4 TX    |
+//! | 10      | I2C0 slave   | SPI0 RX      |
+//! | 11      | I2C0 master  | SPI0 TX      |
+//! | 12      | I2C1 slave   | SPI1 RX      |
+//! | 13      | I2C1 master  | SPI1 TX      |
+//! | 14      | I2C2 slave   | I2C0 slave   |
+//! | 15      | I2C2 master  | I2C0 master  |
+//! | 16      | I2C3 slave   | I2C1 slave   |
+//! | 17      | I2C3 master  | I2C1 master  |
+//! | 18      |              | I2C2 slave   |
+//! | 19      |              | I2C2 master  |
+//! | 20      |              | I2C3 slave   |
+//! | 21      |              | I2C3 master  |
+//! | 22      |              | DAC0         |
+//! | 23      |              | DAC1         |
+//! | 24      |              | CAPT         |
+//!
+//! See user manual, section 12.3.
+//!
+//! # Memory Buffers
+//!
+//! Memory buffers can be used as the source or destination of a transfer, if
+//! they implement [`ReadBuffer`] or [`WriteBuffer`]. Besides `'static`
+//! slices, this includes owning pointers to slices or arrays, like the boxes
+//! of `heapless::pool`. Completed transfers give the buffers back. Dropping a
+//! transfer that hasn't completed aborts it. Owned arrays can't be used by
+//! value, as their address changes when the transfer is moved.
+//!
+//! [`CompatibleWith`]: trait.CompatibleWith.html
+//! [`ReadBuffer`]: trait.ReadBuffer.html
+//! [`WriteBuffer`]: trait.WriteBuffer.html
+
+use core::cell::Cell;
+use core::cmp;
+use core::mem;
+use core::ops::{Deref, DerefMut};
 use core::ptr;
+use core::slice;
 use core::sync::atomic::{compiler_fence, Ordering};
 
-use nb;
+use nb::{self, block};
+use stable_deref_trait::StableDeref;
+use void::Void;
 
 use crate::{
     init_state,
     pac::{
         self,
         dma0::{
-            channel::{CFG, XFERCFG},
-            ACTIVE0, ENABLESET0, SETTRIG0,
+            channel::{xfercfg::WIDTH_A, CFG, XFERCFG},
+            ABORT0, ACTIVE0, BUSY0, ENABLECLR0, ENABLESET0, ERRINT0, INTA0,
+            INTB0, INTENCLR0, INTENSET0, SETTRIG0,
         },
+        Interrupt, NVIC,
     },
     reg_proxy::{Reg, RegProxy},
     syscon,
@@ -121,6 +179,24 @@ impl<'dma> Handle<init_state::Disabled> {
 }
 
 impl Handle<init_state::Enabled> {
+    /// Enable the DMA interrupt
+    ///
+    /// This unmasks the DMA interrupt in the NVIC. Interrupts also need to be
+    /// enabled for each channel, using [`Channel::enable_interrupt`].
+    ///
+    /// [`Channel::enable_interrupt`]: struct.Channel.html#method.enable_interrupt
+    pub fn enable_interrupts(&mut self) {
+        // Safe, as there's no critical section that could be broken by this.
+        unsafe { NVIC::unmask(Interrupt::DMA0) };
+    }
+
+    /// Disable the DMA interrupt
+    ///
+    /// This masks the DMA interrupt in the NVIC.
+    pub fn disable_interrupts(&mut self) {
+        NVIC::mask(Interrupt::DMA0);
+    }
+
     /// Disable the DMA controller
     pub fn disable(
         self,
@@ -136,38 +212,28 @@ impl Handle<init_state::Enabled> {
     }
 }
 
+/// The number of DMA channels
+#[cfg(feature = "82x")]
+const NUM_CHANNELS: usize = 18;
+
+/// The number of DMA channels
+#[cfg(feature = "845")]
+const NUM_CHANNELS: usize = 25;
+
 /// The channel descriptor table
 ///
 /// Contains a descriptor for each DMA channel.
 #[repr(C, align(512))]
-pub struct DescriptorTable([ChannelDescriptor; 18]);
+pub struct DescriptorTable([ChannelDescriptor; NUM_CHANNELS]);
 
 impl DescriptorTable {
     /// Create a new channel descriptor table
     pub const fn new() -> Self {
-        DescriptorTable([
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-            ChannelDescriptor::new(),
-        ])
+        DescriptorTable([ChannelDescriptor::new(); NUM_CHANNELS])
     }
 }
 
+#[derive(Clone, Copy)]
 #[repr(C, align(16))]
 struct ChannelDescriptor {
     config: u32,
@@ -185,6 +251,13 @@ impl ChannelDescriptor {
             next_desc: ptr::null(),
         }
     }
+
+    fn set(&mut self, segment: &Segment, config: u32) {
+        self.config = config;
+        self.source_end = segment.source_end;
+        self.dest_end = segment.dest_end;
+        self.next_desc = ptr::null();
+    }
 }
 
 // `ChannelDescriptor` contains raw pointers, therefore `Send` is not derived
@@ -193,6 +266,27 @@ impl ChannelDescriptor {
 // `cortex_m::interrupt::Mutex`.
 unsafe impl Send for ChannelDescriptor {}
 
+/// A linked descriptor
+///
+/// Transfers that consist of more than one segment, like scatter-gather or
+/// ping-pong transfers, require one linked descriptor for each segment after
+/// the first. Linked descriptors are provided to a channel using
+/// [`Channel::set_descriptors`].
+///
+/// The DMA controller loads linked descriptors from memory while a transfer is
+/// running, which is why they need to be statically allocated.
+///
+/// [`Channel::set_descriptors`]: struct.Channel.html#method.set_descriptors
+#[repr(C, align(16))]
+pub struct Descriptor(ChannelDescriptor);
+
+impl Descriptor {
+    /// Create a new linked descriptor
+    pub const fn new() -> Self {
+        Descriptor(ChannelDescriptor::new())
+    }
+}
+
 /// A DMA channel
 pub struct Channel<T, S>
 where
@@ -201,6 +295,8 @@ where
     ty: T,
     _state: S,
     descriptor: &'static mut ChannelDescriptor,
+    descriptors: &'static mut [Descriptor],
+    interrupt: Option<InterruptFlag>,
 
     // This channel's dedicated registers.
     cfg: RegProxy<T::Cfg>,
@@ -209,8 +305,16 @@ where
     // Shared registers. We restrict our access to the one bit that is dedicated
     // to this channel, so sharing those with other channels should be safe.
     active0: RegProxy<ACTIVE0>,
+    busy0: RegProxy<BUSY0>,
     enableset0: RegProxy<ENABLESET0>,
+    enableclr0: RegProxy<ENABLECLR0>,
+    intenset0: RegProxy<INTENSET0>,
+    intenclr0: RegProxy<INTENCLR0>,
+    inta0: RegProxy<INTA0>,
+    intb0: RegProxy<INTB0>,
+    errint0: RegProxy<ERRINT0>,
     settrig0: RegProxy<SETTRIG0>,
+    abort0: RegProxy<ABORT0>,
 }
 
 impl<T> Channel<T, init_state::Disabled>
@@ -226,13 +330,23 @@ where
             ty: self.ty,
             _state: init_state::Enabled(dma),
             descriptor: self.descriptor,
+            descriptors: self.descriptors,
+            interrupt: self.interrupt,
 
             cfg: self.cfg,
             xfercfg: self.xfercfg,
 
             active0: self.active0,
+            busy0: self.busy0,
             enableset0: self.enableset0,
+            enableclr0: self.enableclr0,
+            intenset0: self.intenset0,
+            intenclr0: self.intenclr0,
+            inta0: self.inta0,
+            intb0: self.intb0,
+            errint0: self.errint0,
             settrig0: self.settrig0,
+            abort0: self.abort0,
         }
     }
 }
@@ -241,161 +355,1059 @@ impl<'dma, T> Channel<T, init_state::Enabled<&'dma Handle>>
 where
     T: ChannelTrait,
 {
+    /// Provide linked descriptors to this channel
+    ///
+    /// Linked descriptors are required for transfers that consist of more than
+    /// one segment, like those started by [`start_scatter`], [`start_gather`],
+    /// and [`start_ping_pong`].
+    ///
+    /// Returns the linked descriptors that were previously provided. If none
+    /// were provided, an empty slice is returned.
+    ///
+    /// [`start_scatter`]: #method.start_scatter
+    /// [`start_gather`]: #method.start_gather
+    /// [`start_ping_pong`]: #method.start_ping_pong
+    pub fn set_descriptors(
+        &mut self,
+        descriptors: &'static mut [Descriptor],
+    ) -> &'static mut [Descriptor] {
+        mem::replace(&mut self.descriptors, descriptors)
+    }
+
+    /// Enable the interrupt for this channel
+    ///
+    /// Once enabled, this channel triggers the DMA interrupt when a transfer
+    /// completes, setting interrupt flag `flag`, or when an error occurs. This
+    /// applies to transfers that are started after this method has been
+    /// called. Ping-pong transfers always use both interrupt flags, one for
+    /// each half of the buffer.
+    ///
+    /// The interrupt will not actually work unless the DMA interrupt has also
+    /// been enabled in the NVIC. See [`Handle::enable_interrupts`]. Use
+    /// [`InterruptStatus::take`] in the interrupt handler, to determine the
+    /// cause of the interrupt.
+    ///
+    /// See user manual, sections 12.6.8 and 12.6.18.
+    ///
+    /// [`Handle::enable_interrupts`]: struct.Handle.html#method.enable_interrupts
+    /// [`InterruptStatus::take`]: struct.InterruptStatus.html#method.take
+    pub fn enable_interrupt(&mut self, flag: InterruptFlag) {
+        self.interrupt = Some(flag);
+
+        // Sound, as we only write this channel's bit.
+        self.intenset0.write(|w| unsafe { w.bits(T::FLAG) });
+    }
+
+    /// Disable the interrupt for this channel
+    pub fn disable_interrupt(&mut self) {
+        self.interrupt = None;
+
+        // Sound, as we only write this channel's bit.
+        self.intenclr0.write(|w| unsafe { w.bits(T::FLAG) });
+    }
+
+    /// Set the priority of this channel
+    ///
+    /// If multiple channels are ready to transfer data, the one with the
+    /// highest priority goes first. `0` is the highest priority, `7` the
+    /// lowest. The default is `0`.
+    ///
+    /// See user manual, section 12.6.16.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `priority` is larger than `7`.
+    pub fn set_priority(&mut self, priority: u8) {
+        assert!(priority <= 7);
+
+        self.cfg
+            .modify(|_, w| unsafe { w.chpriority().bits(priority) });
+    }
+
+    /// Enable hardware triggering for this channel
+    ///
+    /// Transfers that are started after this method has been called are no
+    /// longer started by a software trigger. Instead, each transfer (or burst
+    /// of transfers, depending on `trigger`) waits for the hardware trigger
+    /// that is routed to this channel. Use [`INPUTMUX::route_dma_trigger`] to
+    /// select the trigger source.
+    ///
+    /// See user manual, section 12.6.16.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the burst power of `trigger` is larger than 10.
+    ///
+    /// [`INPUTMUX::route_dma_trigger`]: ../inputmux/struct.INPUTMUX.html#method.route_dma_trigger
+    pub fn enable_hardware_trigger(&mut self, trigger: Trigger) {
+        let burst_power = trigger.burst_power.unwrap_or(0);
+        assert!(burst_power <= 10);
+
+        self.cfg.modify(|_, w| {
+            w.hwtrigen().enabled();
+            w.trigpol()
+                .bit(trigger.polarity == TriggerPolarity::ActiveHigh);
+            w.trigtype().bit(trigger.mode == TriggerMode::Level);
+            w.trigburst().bit(trigger.burst_power.is_some());
+            w.srcburstwrap().bit(trigger.source_wrap);
+            w.dstburstwrap().bit(trigger.dest_wrap);
+            unsafe { w.burstpower().bits(burst_power) }
+        });
+    }
+
+    /// Disable hardware triggering for this channel
+    ///
+    /// Transfers that are started after this method has been called are
+    /// started by a software trigger again. This is the default.
+    pub fn disable_hardware_trigger(&mut self) {
+        self.cfg.modify(|_, w| {
+            w.hwtrigen().disabled();
+            w.trigburst().single();
+            w.srcburstwrap().disabled();
+            w.dstburstwrap().disabled()
+        });
+    }
+
     /// Starts a DMA transfer
     ///
-    /// # Limitations
+    /// Transfers data from `source` to `dest`. Both can be either a peripheral
+    /// (like [`usart::Rx`] or [`usart::Tx`]) or a memory buffer. If at least
+    /// one of them is a peripheral, each word is transferred on request of
+    /// that peripheral. A memory-to-memory transfer, like a `memcpy`, is
+    /// started by a software trigger and runs until it is complete.
     ///
-    /// The length of `source` must be 1024 or less.
-    pub fn start_transfer<D>(
-        self,
-        source: &'static mut [u8],
+    /// The number of words transferred is determined by the memory buffers
+    /// involved. If both `source` and `dest` are memory buffers, the smaller
+    /// one determines the length of the transfer.
+    ///
+    /// The width of the transfer (8, 16, or 32 bits) is determined by the type
+    /// of word that `source` and `dest` agree on. Please refer to [`Strided`],
+    /// if you need address increments other than one word.
+    ///
+    /// A single channel descriptor can transfer up to 1024 words. Longer
+    /// transfers are split into multiple segments, each of which requires one
+    /// linked descriptor (see [`set_descriptors`]). Returns
+    /// [`NotEnoughDescriptors`], if not enough have been provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if neither `source` nor `dest` is a memory buffer, as the length
+    /// of the transfer can't be determined in that case.
+    ///
+    /// [`usart::Rx`]: ../usart/struct.Rx.html
+    /// [`usart::Tx`]: ../usart/struct.Tx.html
+    /// [`Strided`]: struct.Strided.html
+    /// [`set_descriptors`]: #method.set_descriptors
+    /// [`NotEnoughDescriptors`]: struct.NotEnoughDescriptors.html
+    pub fn start_transfer<S, D>(
+        mut self,
+        mut source: S,
         mut dest: D,
-    ) -> Transfer<'dma, T, D>
+    ) -> Result<Transfer<'dma, T, S, D>, NotEnoughDescriptors<'dma, T, S, D>>
     where
-        D: Dest,
+        S: Source + CompatibleWith<T>,
+        D: Dest<Word = S::Word> + CompatibleWith<T>,
     {
         compiler_fence(Ordering::SeqCst);
 
-        // We need to substract 1 from the length below. If the source is empty,
-        // return early to prevent underflow.
-        if source.is_empty() {
-            return Transfer {
+        let periph_request = source.is_peripheral() || dest.is_peripheral();
+        let count =
+            segment_count(source.transfer_count(), dest.transfer_count());
+
+        // Sound, as the segments stay within the bounds of both buffers.
+        let segments = unsafe {
+            split(
+                source.start_addr(),
+                source.increment(),
+                dest.start_addr(),
+                dest.increment(),
+                count,
+            )
+        };
+
+        match self.start_segments(periph_request, segments) {
+            Ok((count, segments)) => {
+                Ok(Transfer::new(self, source, dest, count, segments))
+            }
+            Err(OutOfDescriptors) => Err(NotEnoughDescriptors {
                 channel: self,
                 source,
                 dest,
-            };
+            }),
         }
+    }
 
-        // Configure channel 1 (has request input USART0_TX_DMA)
-        // See user manual, section 12.6.16.
-        self.cfg.write(|w| {
-            w.periphreqen().enabled();
-            w.hwtrigen().disabled();
-            w.trigburst().single();
-            unsafe { w.chpriority().bits(0) }
-        });
+    /// Starts a DMA transfer that scatters data over multiple destinations
+    ///
+    /// Works like [`start_transfer`], except that data from `source` is
+    /// written to each of the destination buffers in turn. If `source` is a
+    /// memory buffer, it is read contiguously, and the transfer ends once it is
+    /// exhausted.
+    ///
+    /// Requires one linked descriptor for each destination buffer after the
+    /// first, plus one for each additional 1024 words in a destination buffer
+    /// (see [`set_descriptors`]). Returns [`NotEnoughDescriptors`], if not
+    /// enough have been provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if neither `source` nor a destination is a memory buffer.
+    ///
+    /// [`start_transfer`]: #method.start_transfer
+    /// [`set_descriptors`]: #method.set_descriptors
+    /// [`NotEnoughDescriptors`]: struct.NotEnoughDescriptors.html
+    pub fn start_scatter<S, D>(
+        mut self,
+        mut source: S,
+        mut dest: Segments<D>,
+    ) -> Result<
+        Transfer<'dma, T, S, Segments<D>>,
+        NotEnoughDescriptors<'dma, T, S, Segments<D>>,
+    >
+    where
+        S: Source + CompatibleWith<T>,
+        D: Dest<Word = S::Word> + CompatibleWith<T>,
+    {
+        compiler_fence(Ordering::SeqCst);
 
-        // Set channel transfer configuration
-        // See user manual, section 12.6.18.
-        self.xfercfg.write(|w| {
-            w.cfgvalid().valid();
-            w.reload().disabled();
-            w.swtrig().not_set();
-            w.clrtrig().cleared();
-            w.setinta().no_effect();
-            w.setintb().no_effect();
-            w.width().bit_8();
-            w.srcinc().width_x_1();
-            w.dstinc().no_increment();
-            unsafe { w.xfercount().bits(source.len() as u16 - 1) }
+        let periph_request =
+            source.is_peripheral() || dest.0.iter().any(|d| d.is_peripheral());
+
+        let source_inc = source.increment();
+        let mut source_addr = source.start_addr();
+        let mut remaining = source.transfer_count();
+
+        let segments = dest.0.iter_mut().flat_map(|dest| {
+            let count = segment_count(remaining, dest.transfer_count());
+
+            // Sound, as the segments stay within the bounds of both buffers.
+            let segments = unsafe {
+                split(
+                    source_addr,
+                    source_inc,
+                    dest.start_addr(),
+                    dest.increment(),
+                    count,
+                )
+            };
+
+            source_addr = source_addr.wrapping_add(count * source_inc.words());
+            remaining = remaining.map(|remaining| remaining - count);
+
+            segments
         });
 
-        let source_end = unsafe { source.as_ptr().add(source.len() - 1) };
+        match self.start_segments(periph_request, segments) {
+            Ok((count, segments)) => {
+                Ok(Transfer::new(self, source, dest, count, segments))
+            }
+            Err(OutOfDescriptors) => Err(NotEnoughDescriptors {
+                channel: self,
+                source,
+                dest,
+            }),
+        }
+    }
+
+    /// Starts a DMA transfer that gathers data from multiple sources
+    ///
+    /// Works like [`start_transfer`], except that data is read from each of
+    /// the source buffers in turn. If `dest` is a memory buffer, it is written
+    /// contiguously, and the transfer ends once it is full.
+    ///
+    /// Requires one linked descriptor for each source buffer after the first,
+    /// plus one for each additional 1024 words in a source buffer (see
+    /// [`set_descriptors`]). Returns [`NotEnoughDescriptors`], if not enough
+    /// have been provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if neither a source nor `dest` is a memory buffer.
+    ///
+    /// [`start_transfer`]: #method.start_transfer
+    /// [`set_descriptors`]: #method.set_descriptors
+    /// [`NotEnoughDescriptors`]: struct.NotEnoughDescriptors.html
+    pub fn start_gather<S, D>(
+        mut self,
+        mut source: Segments<S>,
+        mut dest: D,
+    ) -> Result<
+        Transfer<'dma, T, Segments<S>, D>,
+        NotEnoughDescriptors<'dma, T, Segments<S>, D>,
+    >
+    where
+        S: Source + CompatibleWith<T>,
+        D: Dest<Word = S::Word> + CompatibleWith<T>,
+    {
+        compiler_fence(Ordering::SeqCst);
 
-        // Configure channel descriptor
-        // See user manual, sections 12.5.2 and 12.5.3.
-        self.descriptor.source_end = source_end;
-        self.descriptor.dest_end = dest.end_addr();
+        let periph_request =
+            source.0.iter().any(|s| s.is_peripheral()) || dest.is_peripheral();
+
+        let dest_inc = dest.increment();
+        let mut dest_addr = dest.start_addr();
+        let mut remaining = dest.transfer_count();
+
+        let segments = source.0.iter_mut().flat_map(|source| {
+            let count = segment_count(source.transfer_count(), remaining);
+
+            // Sound, as the segments stay within the bounds of both buffers.
+            let segments = unsafe {
+                split(
+                    source.start_addr(),
+                    source.increment(),
+                    dest_addr,
+                    dest_inc,
+                    count,
+                )
+            };
 
-        // Enable channel 1
-        // See user manual, section 12.6.4.
-        self.enableset0.write(|w| unsafe { w.ena().bits(T::FLAG) });
+            dest_addr = dest_addr.wrapping_add(count * dest_inc.words());
+            remaining = remaining.map(|remaining| remaining - count);
 
-        // Trigger transfer
-        self.settrig0.write(|w| unsafe { w.trig().bits(T::FLAG) });
+            segments
+        });
 
-        Transfer {
-            channel: self,
-            source,
-            dest,
+        match self.start_segments(periph_request, segments) {
+            Ok((count, segments)) => {
+                Ok(Transfer::new(self, source, dest, count, segments))
+            }
+            Err(OutOfDescriptors) => Err(NotEnoughDescriptors {
+                channel: self,
+                source,
+                dest,
+            }),
         }
     }
-}
 
-/// Implemented for each DMA channel
-pub trait ChannelTrait {
-    /// The index of the channel
+    /// Starts a continuous ping-pong transfer
     ///
-    /// This is `0` for channel 0, `1` for channel 1, etc.
-    const INDEX: usize;
+    /// Splits `buffer` into two halves and fills them from `source`
+    /// alternately, without ever stopping. While the DMA controller fills one
+    /// half, the other one can be processed using [`PingPong::peek`]. If
+    /// `buffer` has an odd length, its last word is not used.
+    ///
+    /// Requires one linked descriptor for each half, plus one for each
+    /// additional 1024 words in a half (see [`set_descriptors`]). Returns
+    /// [`NotEnoughDescriptors`], if not enough have been provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `buffer` is shorter than two words.
+    ///
+    /// [`PingPong::peek`]: struct.PingPong.html#method.peek
+    /// [`set_descriptors`]: #method.set_descriptors
+    /// [`NotEnoughDescriptors`]: struct.NotEnoughDescriptors.html
+    pub fn start_ping_pong<S, B>(
+        mut self,
+        mut source: S,
+        mut buffer: B,
+    ) -> Result<PingPong<'dma, T, S, B>, NotEnoughDescriptors<'dma, T, S, B>>
+    where
+        S: Source + CompatibleWith<T>,
+        B: WriteBuffer<Word = S::Word>,
+    {
+        // Sound, as we own the buffer, and nothing else accesses it while
+        // the transfer is ongoing.
+        let (buffer_addr, buffer_len) = unsafe { buffer.write_buffer() };
 
-    /// The flag for the channel
+        assert!(buffer_len >= 2);
+
+        compiler_fence(Ordering::SeqCst);
+
+        let half = buffer_len / 2;
+        let source_addr = source.start_addr();
+        let source_inc = source.increment();
+
+        // Sound, as the segments stay within the bounds of both buffers.
+        let (first, second) = unsafe {
+            (
+                split(
+                    source_addr,
+                    source_inc,
+                    buffer_addr,
+                    Increment::Width1,
+                    half,
+                ),
+                split(
+                    source_addr,
+                    source_inc,
+                    buffer_addr.add(half),
+                    Increment::Width1,
+                    half,
+                ),
+            )
+        };
+
+        // The completion of each half is signalled by a different interrupt
+        // flag. This allows `PingPong` to detect if it has fallen behind.
+        let last = (half - 1) / MAX_COUNT;
+        let segments = first
+            .enumerate()
+            .map(|(i, segment)| (segment, i == last, false))
+            .chain(
+                second
+                    .enumerate()
+                    .map(|(i, segment)| (segment, false, i == last)),
+            );
+
+        match self.start_loop(source.is_peripheral(), segments) {
+            Ok(()) => Ok(PingPong {
+                channel: self,
+                source,
+                buffer,
+                next: 0,
+            }),
+            Err(OutOfDescriptors) => Err(NotEnoughDescriptors {
+                channel: self,
+                source,
+                dest: buffer,
+            }),
+        }
+    }
+
+    /// Starts a transfer that repeats until stopped
     ///
-    /// This is `0x1` for channel 0, `0x2` for channel 2, `0x4` for channel 3,
-    /// etc.
-    const FLAG: u32;
+    /// Transfers data from `source` to `dest`, like [`start_transfer`], but
+    /// starts over from the beginning once the transfer is complete, without
+    /// ever stopping. This is most useful together with a hardware trigger
+    /// (see [`enable_hardware_trigger`]), to output a periodic pattern at a
+    /// fixed rate.
+    ///
+    /// Requires one linked descriptor, plus one for each additional 1024 words
+    /// (see [`set_descriptors`]). Returns [`NotEnoughDescriptors`], if not
+    /// enough have been provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if neither `source` nor `dest` is a memory buffer, or if the
+    /// transfer would be empty.
+    ///
+    /// [`start_transfer`]: #method.start_transfer
+    /// [`enable_hardware_trigger`]: #method.enable_hardware_trigger
+    /// [`set_descriptors`]: #method.set_descriptors
+    /// [`NotEnoughDescriptors`]: struct.NotEnoughDescriptors.html
+    pub fn start_repeating<S, D>(
+        mut self,
+        mut source: S,
+        mut dest: D,
+    ) -> Result<Repeating<'dma, T, S, D>, NotEnoughDescriptors<'dma, T, S, D>>
+    where
+        S: Source + CompatibleWith<T>,
+        D: Dest<Word = S::Word> + CompatibleWith<T>,
+    {
+        let count =
+            segment_count(source.transfer_count(), dest.transfer_count());
+        assert!(count > 0);
 
-    /// The type that represents this channel's CFG register
-    type Cfg: Reg<Target = CFG>;
+        compiler_fence(Ordering::SeqCst);
 
-    /// The type that represents this channel's XFERCFG register
-    type Xfercfg: Reg<Target = XFERCFG>;
-}
+        // Sound, as the segments stay within the bounds of both buffers.
+        let segments = unsafe {
+            split(
+                source.start_addr(),
+                source.increment(),
+                dest.start_addr(),
+                dest.increment(),
+                count,
+            )
+        };
+        let segments = segments.map(|segment| (segment, false, false));
+
+        let periph_request = source.is_peripheral() || dest.is_peripheral();
+        match self.start_loop(periph_request, segments) {
+            Ok(()) => Ok(Repeating {
+                channel: self,
+                source,
+                dest,
+            }),
+            Err(OutOfDescriptors) => Err(NotEnoughDescriptors {
+                channel: self,
+                source,
+                dest,
+            }),
+        }
+    }
 
-macro_rules! channels {
-    ($($field:ident, $name:ident, $index:expr, $cfg:ident, $xfercfg:ident;)*) => {
-        /// Provides access to all channels
-        #[allow(missing_docs)]
-        pub struct Channels {
-            $(pub $field: Channel<$name, init_state::Disabled>,)*
+    /// Starts a transfer that loops over `segments` without ever stopping
+    ///
+    /// Each segment comes with the interrupt flags A and B it sets, once it
+    /// is exhausted. `segments` must not be empty.
+    fn start_loop<I>(
+        &mut self,
+        periph_request: bool,
+        segments: I,
+    ) -> Result<(), OutOfDescriptors>
+    where
+        I: Iterator<Item = (Segment, bool, bool)>,
+    {
+        // The descriptors link to each other in a loop. The channel descriptor
+        // can't be part of that loop, as the DMA controller may use it as its
+        // working copy of the active descriptor. Each segment needs a linked
+        // descriptor, and the channel descriptor starts out as a copy of the
+        // first one.
+        let mut count = 0;
+        for (segment, inta, intb) in segments {
+            let descriptor =
+                &mut self.descriptors.get_mut(count).ok_or(OutOfDescriptors)?.0;
+            descriptor.set(&segment, segment.config(true, inta, intb));
+            count += 1;
         }
 
-        impl Channels {
-            fn new(descriptors: &'static mut DescriptorTable) -> Self {
-                let mut descriptors = (&mut descriptors.0).into_iter();
+        for i in 0..count {
+            let next: *const ChannelDescriptor =
+                &self.descriptors[(i + 1) % count].0;
+            self.descriptors[i].0.next_desc = next;
+        }
 
-                Channels {
-                    $(
-                        $field: Channel {
-                            ty        : $name(()),
-                            _state    : init_state::Disabled,
-                            descriptor: descriptors.next().unwrap(),
+        *self.descriptor = self.descriptors[0].0;
+        let xfercfg = self.descriptor.config;
 
-                            cfg    : RegProxy::new(),
-                            xfercfg: RegProxy::new(),
+        self.clear_flags();
+        self.start(periph_request, xfercfg);
 
-                            active0   : RegProxy::new(),
-                            enableset0: RegProxy::new(),
-                            settrig0  : RegProxy::new(),
-                        },
-                    )*
-                }
+        Ok(())
+    }
+
+    /// Starts a transfer that consists of `segments`
+    ///
+    /// Returns the number of words and the number of segments.
+    fn start_segments<I>(
+        &mut self,
+        periph_request: bool,
+        segments: I,
+    ) -> Result<(usize, usize), OutOfDescriptors>
+    where
+        I: Iterator<Item = Segment>,
+    {
+        let mut segments = segments.peekable();
+
+        // The last segment sets the selected interrupt flag, if any.
+        let (inta, intb) = match self.interrupt {
+            Some(InterruptFlag::A) => (true, false),
+            Some(InterruptFlag::B) => (false, true),
+            None => (false, false),
+        };
+        let config = |segment: &Segment, reload: bool| {
+            segment.config(reload, inta && !reload, intb && !reload)
+        };
+
+        let first = match segments.next() {
+            Some(segment) => segment,
+            None => {
+                // There's nothing to transfer. Make the transfer count look
+                // like that of a finished transfer, so `Transfer::is_done`
+                // works as expected.
+                //
+                // Sound, as the configuration is not marked as valid.
+                self.xfercfg.write(|w| unsafe { w.bits(0x3ff << 16) });
+                self.descriptor.next_desc = ptr::null();
+                return Ok((0, 0));
             }
+        };
+        let xfercfg = config(&first, segments.peek().is_some());
+        let mut count = first.count;
+        let mut num_segments = 1;
+
+        // Configure channel descriptor
+        // See user manual, sections 12.5.2 and 12.5.3.
+        self.descriptor.set(&first, xfercfg);
+
+        // Configure and link the descriptors for all following segments
+        let mut prev = &mut *self.descriptor;
+        let mut descriptors = self.descriptors.iter_mut();
+        while let Some(segment) = segments.next() {
+            let descriptor = &mut descriptors.next().ok_or(OutOfDescriptors)?.0;
+
+            descriptor
+                .set(&segment, config(&segment, segments.peek().is_some()));
+            prev.next_desc = descriptor;
+            prev = descriptor;
+
+            count += segment.count;
+            num_segments += 1;
         }
 
+        self.start(periph_request, xfercfg);
 
-        $(
-            /// This struct is an implementation detail that shouldn't be used by user
-            pub struct $xfercfg;
+        Ok((count, num_segments))
+    }
 
-            reg_cluster!($xfercfg, XFERCFG, pac::DMA0, $field, xfercfg);
+    fn start(&mut self, periph_request: bool, xfercfg: u32) {
+        // Configure channel. The trigger configuration is left as it is.
+        // See user manual, section 12.6.16.
+        self.cfg.modify(|_, w| {
+            if periph_request {
+                w.periphreqen().enabled()
+            } else {
+                w.periphreqen().disabled()
+            }
+        });
 
-            /// This struct is an implementation detail that shouldn't be used by user
-            pub struct $cfg;
+        // Set channel transfer configuration
+        // See user manual, section 12.6.18.
+        //
+        // Sound, as `Segment::config` creates a valid configuration.
+        self.xfercfg.write(|w| unsafe { w.bits(xfercfg) });
 
-            reg_cluster!($cfg, CFG, pac::DMA0, $field, cfg);
+        // Enable channel
+        // See user manual, section 12.6.4.
+        self.enableset0.write(|w| unsafe { w.ena().bits(T::FLAG) });
 
-            /// Identifies a DMA channel
-            pub struct $name(());
+        // Trigger transfer, unless we're waiting for a hardware trigger
+        if self.cfg.read().hwtrigen().bit_is_clear() {
+            self.settrig0.write(|w| unsafe { w.trig().bits(T::FLAG) });
+        }
+    }
 
-            impl ChannelTrait for $name {
-                const INDEX: usize = $index;
-                const FLAG : u32   = 0x1 << Self::INDEX;
+    /// Aborts the current transfer
+    ///
+    /// Returns the number of words that the active segment hadn't transferred
+    /// yet, and the value of XFERCFG, which describes that segment.
+    fn abort(&mut self) -> (usize, u32) {
+        // Disable the channel and wait until it's no longer busy. The channel
+        // is stopped after that, and its state can be inspected before it's
+        // aborted.
+        // See user manual, section 12.6.11.
+        //
+        // Sound, as we only write this channel's bit.
+        self.enableclr0.write(|w| unsafe { w.bits(T::FLAG) });
+        while self.busy0.read().bits() & T::FLAG != 0 {}
 
-                type Cfg     = $cfg;
-                type Xfercfg = $xfercfg;
-            }
-        )*
+        let xfercfg = self.xfercfg.read().bits();
+        let remaining = self.segment_remaining(xfercfg);
+
+        // Sound, as we only write this channel's bit.
+        self.abort0.write(|w| unsafe { w.bits(T::FLAG) });
+        self.clear_flags();
+        self.errint0.write(|w| unsafe { w.bits(T::FLAG) });
+
+        (remaining, xfercfg)
+    }
+
+    /// The number of words that the active segment has yet to transfer
+    fn segment_remaining(&self, xfercfg: u32) -> usize {
+        // The transfer count in XFERCFG is the number of words remaining in
+        // the current descriptor, minus one. It wraps around to 0x3ff, once
+        // the last descriptor is exhausted.
+        // See user manual, section 12.6.18.
+        let active = self.active0.read().act().bits() & T::FLAG != 0;
+        let count = xfercount(xfercfg);
+        if !active && count == 0x3ff {
+            0
+        } else {
+            count + 1
+        }
+    }
+
+    fn clear_flags(&mut self) {
+        // Sound, as we only write this channel's bit.
+        self.inta0.write(|w| unsafe { w.bits(T::FLAG) });
+        self.intb0.write(|w| unsafe { w.bits(T::FLAG) });
     }
 }
 
-#[cfg(feature = "82x")]
-// The channels must always be specified in order, from lowest to highest, as
-// the channel descriptors are assigned based on that order.
-channels!(
-    channel0 , Channel0 ,  0, CFG0 , XFERCFG0 ;
-    channel1 , Channel1 ,  1, CFG1 , XFERCFG1 ;
-    channel2 , Channel2 ,  2, CFG2 , XFERCFG2 ;
+/// One of the two interrupt flags of a DMA channel
+///
+/// Used by [`Channel::enable_interrupt`].
+///
+/// [`Channel::enable_interrupt`]: struct.Channel.html#method.enable_interrupt
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InterruptFlag {
+    /// Interrupt flag A
+    A,
+
+    /// Interrupt flag B
+    B,
+}
+
+/// The interrupt flags of all DMA channels
+///
+/// Each field has one bit per channel. Channel 0 is represented by the least
+/// significant bit, channel 1 by the next bit, and so forth.
+///
+/// See user manual, sections 12.6.9, 12.6.12, and 12.6.13.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InterruptStatus {
+    /// Interrupt flag A
+    pub a: u32,
+
+    /// Interrupt flag B
+    pub b: u32,
+
+    /// Error interrupt flag
+    pub error: u32,
+}
+
+impl InterruptStatus {
+    /// Read and clear the interrupt flags of all channels
+    ///
+    /// This is intended to be called from the DMA interrupt handler. Only the
+    /// flags that are returned are cleared, so no flags that are set while this
+    /// method runs can be lost.
+    ///
+    /// Please note that [`Transfer::wait`] can't report errors that have been
+    /// cleared by this method, and that [`PingPong::peek`] relies on the
+    /// interrupt flags of its channel. Don't use this method while a
+    /// ping-pong transfer is running.
+    ///
+    /// [`Transfer::wait`]: struct.Transfer.html#method.wait
+    /// [`PingPong::peek`]: struct.PingPong.html#method.peek
+    pub fn take() -> Self {
+        // Sound, as the flag registers are write-1-to-clear, and we only clear
+        // the flags we've read.
+        let dma = unsafe { &*pac::DMA0::ptr() };
+
+        let status = InterruptStatus {
+            a: dma.inta0.read().bits(),
+            b: dma.intb0.read().bits(),
+            error: dma.errint0.read().bits(),
+        };
+
+        dma.inta0.write(|w| unsafe { w.bits(status.a) });
+        dma.intb0.write(|w| unsafe { w.bits(status.b) });
+        dma.errint0.write(|w| unsafe { w.bits(status.error) });
+
+        status
+    }
+
+    /// Indicates whether interrupt flag A is set for the given channel
+    pub fn is_a<T>(&self) -> bool
+    where
+        T: ChannelTrait,
+    {
+        self.a & T::FLAG != 0
+    }
+
+    /// Indicates whether interrupt flag B is set for the given channel
+    pub fn is_b<T>(&self) -> bool
+    where
+        T: ChannelTrait,
+    {
+        self.b & T::FLAG != 0
+    }
+
+    /// Indicates whether the error flag is set for the given channel
+    pub fn is_error<T>(&self) -> bool
+    where
+        T: ChannelTrait,
+    {
+        self.error & T::FLAG != 0
+    }
+}
+
+/// Hardware trigger configuration for a DMA channel
+///
+/// Used by [`Channel::enable_hardware_trigger`].
+///
+/// See user manual, section 12.6.16.
+///
+/// [`Channel::enable_hardware_trigger`]: struct.Channel.html#method.enable_hardware_trigger
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Trigger {
+    /// The polarity of the trigger
+    pub polarity: TriggerPolarity,
+
+    /// Whether the trigger is edge-sensitive or level-sensitive
+    pub mode: TriggerMode,
+
+    /// The size of the burst that is transferred on each trigger
+    ///
+    /// If this is `None`, a single trigger starts the whole transfer that is
+    /// described by the current descriptor. `Some(n)` means that a burst of
+    /// 2^n words is transferred on each trigger, so `Some(0)` transfers a
+    /// single word. `n` must not be larger than 10.
+    pub burst_power: Option<u8>,
+
+    /// Whether the source address wraps after each burst
+    ///
+    /// If this is enabled, the source address is reset to the start of the
+    /// source after each burst, instead of continuing from where the burst
+    /// ended.
+    pub source_wrap: bool,
+
+    /// Whether the destination address wraps after each burst
+    ///
+    /// If this is enabled, the destination address is reset to the start of
+    /// the destination after each burst, instead of continuing from where the
+    /// burst ended.
+    pub dest_wrap: bool,
+}
+
+impl Trigger {
+    /// Transfer a single word on each rising edge
+    pub fn rising_edge() -> Self {
+        Trigger {
+            polarity: TriggerPolarity::ActiveHigh,
+            mode: TriggerMode::Edge,
+            burst_power: Some(0),
+            source_wrap: false,
+            dest_wrap: false,
+        }
+    }
+
+    /// Transfer a single word on each falling edge
+    pub fn falling_edge() -> Self {
+        Trigger {
+            polarity: TriggerPolarity::ActiveLow,
+            ..Self::rising_edge()
+        }
+    }
+}
+
+/// The polarity of a DMA hardware trigger
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriggerPolarity {
+    /// Triggers on a falling edge or a low level
+    ActiveLow,
+
+    /// Triggers on a rising edge or a high level
+    ActiveHigh,
+}
+
+/// Determines whether a DMA hardware trigger reacts to edges or levels
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriggerMode {
+    /// The trigger reacts to edges
+    Edge,
+
+    /// The trigger reacts to levels
+    Level,
+}
+
+/// The maximum number of words that a single descriptor can transfer
+///
+/// See user manual, section 12.6.18.
+const MAX_COUNT: usize = 1024;
+
+/// Split a transfer into segments that fit into a descriptor each
+///
+/// Unsafe, as the caller must make sure that `count` words, with the
+/// respective address increment applied, fit into the source and destination.
+/// If `count` is zero, no segments are returned.
+unsafe fn split<W>(
+    source: *const W,
+    source_inc: Increment,
+    dest: *mut W,
+    dest_inc: Increment,
+    count: usize,
+) -> impl Iterator<Item = Segment>
+where
+    W: Word,
+{
+    (0..count).step_by(MAX_COUNT).map(move |offset| {
+        Segment::new(
+            source.add(offset * source_inc.words()),
+            source_inc,
+            dest.add(offset * dest_inc.words()),
+            dest_inc,
+            cmp::min(count - offset, MAX_COUNT),
+        )
+    })
+}
+
+/// A segment of a transfer, to be written into a channel descriptor
+struct Segment {
+    source_end: *const u8,
+    dest_end: *mut u8,
+    source_inc: Increment,
+    dest_inc: Increment,
+    width: WIDTH_A,
+    count: usize,
+}
+
+impl Segment {
+    /// Create a segment
+    ///
+    /// Unsafe, as the caller must make sure that `count` words, with the
+    /// respective address increment applied, fit into the source and
+    /// destination. `count` must not be zero.
+    unsafe fn new<W>(
+        source: *const W,
+        source_inc: Increment,
+        dest: *mut W,
+        dest_inc: Increment,
+        count: usize,
+    ) -> Self
+    where
+        W: Word,
+    {
+        // The DMA controller expects the address of the last word of each
+        // buffer, which depends on the address increment.
+        Segment {
+            source_end: source.add((count - 1) * source_inc.words())
+                as *const u8,
+            dest_end: dest.add((count - 1) * dest_inc.words()) as *mut u8,
+            source_inc,
+            dest_inc,
+            width: W::WIDTH,
+            count,
+        }
+    }
+
+    /// The value of XFERCFG for this segment
+    ///
+    /// See user manual, section 12.6.18.
+    fn config(&self, reload: bool, setinta: bool, setintb: bool) -> u32 {
+        // CFGVALID
+        let mut config = 0x1;
+
+        // If another descriptor is loaded after this one, the trigger needs to
+        // remain set. Otherwise, it's cleared once this descriptor is
+        // exhausted.
+        if reload {
+            config |= 0x1 << 1;
+        } else {
+            config |= 0x1 << 3;
+        }
+
+        if setinta {
+            config |= 0x1 << 4;
+        }
+        if setintb {
+            config |= 0x1 << 5;
+        }
+
+        config |= (u8::from(self.width) as u32) << 8;
+        config |= self.source_inc.bits() << 12;
+        config |= self.dest_inc.bits() << 14;
+        config |= ((self.count - 1) as u32 & 0x3ff) << 16;
+
+        config
+    }
+}
+
+/// The transfer count of a value of XFERCFG
+///
+/// This is the number of words remaining, minus one.
+fn xfercount(xfercfg: u32) -> usize {
+    ((xfercfg >> 16) & 0x3ff) as usize
+}
+
+fn segment_count(source: Option<usize>, dest: Option<usize>) -> usize {
+    match (source, dest) {
+        (Some(source), Some(dest)) => cmp::min(source, dest),
+        (Some(count), None) | (None, Some(count)) => count,
+        (None, None) => {
+            panic!("Source or destination must be a memory buffer")
+        }
+    }
+}
+
+struct OutOfDescriptors;
+
+/// Returned, if a transfer requires more linked descriptors than available
+///
+/// Contains the channel, source, and destination that were passed to the
+/// method that returned this error, so they are not lost.
+pub struct NotEnoughDescriptors<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    /// The channel that was supposed to perform the transfer
+    pub channel: Channel<T, init_state::Enabled<&'dma Handle>>,
+
+    /// The source of the transfer
+    pub source: S,
+
+    /// The destination of the transfer
+    pub dest: D,
+}
+
+/// Implemented for each DMA channel
+pub trait ChannelTrait {
+    /// The index of the channel
+    ///
+    /// This is `0` for channel 0, `1` for channel 1, etc.
+    const INDEX: usize;
+
+    /// The flag for the channel
+    ///
+    /// This is `0x1` for channel 0, `0x2` for channel 2, `0x4` for channel 3,
+    /// etc.
+    const FLAG: u32;
+
+    /// The type that represents this channel's CFG register
+    type Cfg: Reg<Target = CFG>;
+
+    /// The type that represents this channel's XFERCFG register
+    type Xfercfg: Reg<Target = XFERCFG>;
+}
+
+macro_rules! channels {
+    ($($field:ident, $name:ident, $index:expr, $cfg:ident, $xfercfg:ident;)*) => {
+        /// Provides access to all channels
+        #[allow(missing_docs)]
+        pub struct Channels {
+            $(pub $field: Channel<$name, init_state::Disabled>,)*
+        }
+
+        impl Channels {
+            fn new(descriptors: &'static mut DescriptorTable) -> Self {
+                let mut descriptors = (&mut descriptors.0).into_iter();
+
+                Channels {
+                    $(
+                        $field: Channel {
+                            ty        : $name(()),
+                            _state    : init_state::Disabled,
+                            descriptor : descriptors.next().unwrap(),
+                            descriptors: &mut [],
+                            interrupt  : None,
+
+                            cfg    : RegProxy::new(),
+                            xfercfg: RegProxy::new(),
+
+                            active0   : RegProxy::new(),
+                            busy0     : RegProxy::new(),
+                            enableset0: RegProxy::new(),
+                            enableclr0: RegProxy::new(),
+                            intenset0 : RegProxy::new(),
+                            intenclr0 : RegProxy::new(),
+                            inta0     : RegProxy::new(),
+                            intb0     : RegProxy::new(),
+                            errint0   : RegProxy::new(),
+                            settrig0  : RegProxy::new(),
+                            abort0    : RegProxy::new(),
+                        },
+                    )*
+                }
+            }
+        }
+
+
+        $(
+            /// This struct is an implementation detail that shouldn't be used by user
+            pub struct $xfercfg;
+
+            reg_cluster!($xfercfg, XFERCFG, pac::DMA0, $field, xfercfg);
+
+            /// This struct is an implementation detail that shouldn't be used by user
+            pub struct $cfg;
+
+            reg_cluster!($cfg, CFG, pac::DMA0, $field, cfg);
+
+            /// Identifies a DMA channel
+            pub struct $name(());
+
+            impl ChannelTrait for $name {
+                const INDEX: usize = $index;
+                const FLAG : u32   = 0x1 << Self::INDEX;
+
+                type Cfg     = $cfg;
+                type Xfercfg = $xfercfg;
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "82x")]
+// The channels must always be specified in order, from lowest to highest, as
+// the channel descriptors are assigned based on that order.
+channels!(
+    channel0 , Channel0 ,  0, CFG0 , XFERCFG0 ;
+    channel1 , Channel1 ,  1, CFG1 , XFERCFG1 ;
+    channel2 , Channel2 ,  2, CFG2 , XFERCFG2 ;
     channel3 , Channel3 ,  3, CFG3 , XFERCFG3 ;
     channel4 , Channel4 ,  4, CFG4 , XFERCFG4 ;
     channel5 , Channel5 ,  5, CFG5 , XFERCFG5 ;
@@ -444,73 +1456,984 @@ channels!(
     channel24, Channel24, 24, CFG24, XFERCFG24;
 );
 
-/// A destination for a DMA transfer
-pub trait Dest {
-    /// The error that can occur while waiting for the destination to be idle
+/// A word that can be transferred by the DMA controller
+///
+/// Implemented for `u8`, `u16`, and `u32`, which correspond to the 8-bit,
+/// 16-bit, and 32-bit transfer widths supported by the DMA controller.
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+pub trait Word: Copy {
+    /// The transfer width that corresponds to this type
+    const WIDTH: WIDTH_A;
+}
+
+impl Word for u8 {
+    const WIDTH: WIDTH_A = WIDTH_A::BIT_8;
+}
+
+impl Word for u16 {
+    const WIDTH: WIDTH_A = WIDTH_A::BIT_16;
+}
+
+impl Word for u32 {
+    const WIDTH: WIDTH_A = WIDTH_A::BIT_32;
+}
+
+/// The address increment of a DMA source or destination
+///
+/// Determines by how much the address of a source or destination is
+/// incremented after each transferred word.
+///
+/// See user manual, section 12.6.18.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Increment {
+    /// The address is not incremented
+    ///
+    /// This is used for peripheral registers.
+    None,
+
+    /// The address is incremented by the width of one word
+    Width1,
+
+    /// The address is incremented by the width of two words
+    Width2,
+
+    /// The address is incremented by the width of four words
+    Width4,
+}
+
+impl Increment {
+    fn words(self) -> usize {
+        match self {
+            Increment::None => 0,
+            Increment::Width1 => 1,
+            Increment::Width2 => 2,
+            Increment::Width4 => 4,
+        }
+    }
+
+    /// The value of the SRCINC or DSTINC field in XFERCFG
+    fn bits(self) -> u32 {
+        match self {
+            Increment::None => 0,
+            Increment::Width1 => 1,
+            Increment::Width2 => 2,
+            Increment::Width4 => 3,
+        }
+    }
+}
+
+/// A source or destination of a DMA transfer
+///
+/// This is the common base of [`Source`] and [`Dest`].
+///
+/// [`Source`]: trait.Source.html
+/// [`Dest`]: trait.Dest.html
+pub trait Endpoint {
+    /// The error that can occur while waiting for the endpoint to be idle
     type Error;
 
-    /// Wait for the destination to be idle
+    /// Wait for the endpoint to be idle
     fn wait(&mut self) -> nb::Result<(), Self::Error>;
+}
 
-    /// The last byte of the destination's memory range
-    fn end_addr(&mut self) -> *mut u8;
+/// Indicates that a source or destination can be used with DMA channel `C`
+///
+/// Each DMA channel is hard-wired to the DMA request of one peripheral (see
+/// [module documentation]). Peripheral sources and destinations only implement
+/// this trait for their respective channel, which makes sure that they can't
+/// be used with the wrong channel. Memory buffers can be used with any
+/// channel.
+///
+/// [module documentation]: index.html
+pub trait CompatibleWith<C>
+where
+    C: ChannelTrait,
+{
 }
 
-/// A DMA transfer
-pub struct Transfer<'dma, T, D>
+impl<C, B> CompatibleWith<C> for B
 where
-    T: ChannelTrait,
+    C: ChannelTrait,
+    B: ReadBuffer,
 {
-    channel: Channel<T, init_state::Enabled<&'dma Handle>>,
-    source: &'static mut [u8],
-    dest: D,
 }
 
-impl<'dma, T, D> Transfer<'dma, T, D>
+impl<C, B> CompatibleWith<C> for Strided<B> where C: ChannelTrait {}
+
+impl<C, B> CompatibleWith<C> for Segments<B>
 where
-    T: ChannelTrait,
-    D: Dest,
+    C: ChannelTrait,
+    B: CompatibleWith<C>,
 {
-    /// Waits for the transfer to finish
-    pub fn wait(
-        mut self,
-    ) -> Result<
-        (
-            Channel<T, init_state::Enabled<&'dma Handle>>,
-            &'static mut [u8],
-            D,
-        ),
-        D::Error,
-    > {
-        // There's an error interrupt status register. Maybe we should check
-        // this here, but I have no idea whether that actually makes sense:
-        // 1. As of this writing, we're not enabling any interrupts. I don't
-        //    know if the flag would still be set in that case.
-        // 2. The documentation is quiet about what could cause an error in the
-        //    first place.
-        //
-        // This needs some further looking into.
+}
 
-        while self.channel.active0.read().act().bits() & T::FLAG != 0 {}
+/// A source for a DMA transfer
+pub trait Source: Endpoint {
+    /// The type of word that is read from the source
+    type Word: Word;
 
-        loop {
-            match self.dest.wait() {
-                Err(nb::Error::WouldBlock) => continue,
-                Ok(()) => break,
+    /// Indicates whether the source is a peripheral
+    ///
+    /// If this returns `true`, each word is transferred on request of the
+    /// peripheral.
+    fn is_peripheral(&self) -> bool;
 
-                Err(nb::Error::Other(error)) => {
-                    compiler_fence(Ordering::SeqCst);
-                    return Err(error);
-                }
-            }
-        }
+    /// The address increment after each word that is read
+    fn increment(&self) -> Increment;
 
-        compiler_fence(Ordering::SeqCst);
+    /// The number of words that can be read from the source
+    ///
+    /// Returns `None`, if the source has no limit, as is the case for
+    /// peripherals.
+    fn transfer_count(&self) -> Option<usize>;
+
+    /// The address of the first word that is read
+    fn start_addr(&mut self) -> *const Self::Word;
+}
+
+/// A destination for a DMA transfer
+pub trait Dest: Endpoint {
+    /// The type of word that is written to the destination
+    type Word: Word;
 
-        Ok((self.channel, self.source, self.dest))
+    /// Indicates whether the destination is a peripheral
+    ///
+    /// If this returns `true`, each word is transferred on request of the
+    /// peripheral.
+    fn is_peripheral(&self) -> bool;
+
+    /// The address increment after each word that is written
+    fn increment(&self) -> Increment;
+
+    /// The number of words that can be written to the destination
+    ///
+    /// Returns `None`, if the destination has no limit, as is the case for
+    /// peripherals.
+    fn transfer_count(&self) -> Option<usize>;
+
+    /// The address of the first word that is written
+    fn start_addr(&mut self) -> *mut Self::Word;
+}
+
+/// A memory buffer that can be read by the DMA controller
+///
+/// Implemented for all types that dereference to a slice or an array of
+/// [`Word`]s, and whose target address doesn't change when they are moved
+/// (see [`StableDeref`]). This includes `&'static [W]`, `&'static mut [W]`,
+/// references to arrays, and owning pointers like `heapless::pool::Box`.
+///
+/// The buffer type must be `'static`, even if the memory it points to is not
+/// a `static`. If a transfer is dropped, it aborts the DMA transfer before the
+/// buffer is dropped. If a transfer is leaked using `mem::forget`, the buffer
+/// is leaked with it, and since it is `'static`, the memory it points to is
+/// never freed or reused. Either way, the memory stays valid for as long as
+/// the DMA controller accesses it.
+///
+/// Owned arrays can't be used directly, as they would move along with the
+/// transfer. Put them into a `static`, or into a pool allocation instead.
+///
+/// This trait is modeled after the trait of the same name from the
+/// `embedded-dma` crate.
+///
+/// # Safety
+///
+/// Implementations must return the same address and length on every call,
+/// even if the buffer has been moved in the meantime. The memory must stay
+/// valid for as long as the buffer isn't dropped.
+///
+/// [`Word`]: trait.Word.html
+/// [`StableDeref`]: https://docs.rs/stable_deref_trait/1.1.1/stable_deref_trait/trait.StableDeref.html
+pub unsafe trait ReadBuffer {
+    /// The type of word that is stored in the buffer
+    type Word: Word;
+
+    /// Returns the address and length, in words, of the buffer
+    ///
+    /// # Safety
+    ///
+    /// The caller must not access the memory in a way that conflicts with
+    /// how the buffer is accessed otherwise. Specifically, it must not write
+    /// to the memory.
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize);
+}
+
+/// A memory buffer that can be written by the DMA controller
+///
+/// Implemented for all types that implement [`ReadBuffer`] and allow mutable
+/// access to their target. Please refer to the documentation of
+/// [`ReadBuffer`] for details.
+///
+/// # Safety
+///
+/// The same requirements as for [`ReadBuffer`] apply. In addition, the
+/// address returned by `write_buffer` must be the same as the one returned
+/// by `read_buffer`.
+///
+/// [`ReadBuffer`]: trait.ReadBuffer.html
+pub unsafe trait WriteBuffer: ReadBuffer {
+    /// Returns the address and length, in words, of the buffer
+    ///
+    /// # Safety
+    ///
+    /// The caller must not access the memory in a way that conflicts with
+    /// how the buffer is accessed otherwise.
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize);
+}
+
+unsafe impl<B, T> ReadBuffer for B
+where
+    B: Deref<Target = T> + StableDeref + 'static,
+    T: ReadTarget + ?Sized,
+{
+    type Word = T::Word;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        self.deref().as_read_buffer()
+    }
+}
+
+unsafe impl<B, T> WriteBuffer for B
+where
+    B: DerefMut<Target = T> + StableDeref + 'static,
+    T: WriteTarget + ?Sized,
+{
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        self.deref_mut().as_write_buffer()
+    }
+}
+
+/// Implemented for types that a [`ReadBuffer`] can dereference to
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+///
+/// [`ReadBuffer`]: trait.ReadBuffer.html
+pub unsafe trait ReadTarget {
+    /// The type of word that is stored in the target
+    type Word: Word;
+
+    /// Returns the address and length, in words, of the target
+    fn as_read_buffer(&self) -> (*const Self::Word, usize);
+}
+
+/// Implemented for types that a [`WriteBuffer`] can dereference to
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+///
+/// [`WriteBuffer`]: trait.WriteBuffer.html
+pub unsafe trait WriteTarget: ReadTarget {
+    /// Returns the address and length, in words, of the target
+    fn as_write_buffer(&mut self) -> (*mut Self::Word, usize);
+}
+
+unsafe impl<W> ReadTarget for [W]
+where
+    W: Word,
+{
+    type Word = W;
+
+    fn as_read_buffer(&self) -> (*const Self::Word, usize) {
+        (self.as_ptr(), self.len())
+    }
+}
+
+unsafe impl<W> WriteTarget for [W]
+where
+    W: Word,
+{
+    fn as_write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        (self.as_mut_ptr(), self.len())
+    }
+}
+
+macro_rules! array_targets {
+    ($($len:expr,)*) => {
+        $(
+            unsafe impl<W> ReadTarget for [W; $len]
+            where
+                W: Word,
+            {
+                type Word = W;
+
+                fn as_read_buffer(&self) -> (*const Self::Word, usize) {
+                    (self.as_ptr(), $len)
+                }
+            }
+
+            unsafe impl<W> WriteTarget for [W; $len]
+            where
+                W: Word,
+            {
+                fn as_write_buffer(&mut self) -> (*mut Self::Word, usize) {
+                    (self.as_mut_ptr(), $len)
+                }
+            }
+        )*
+    };
+}
+
+array_targets!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+    21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 64, 128, 256, 512, 1024,
+    2048, 4096, 8192, 16384,
+);
+
+impl<B> Endpoint for B
+where
+    B: ReadBuffer,
+{
+    type Error = Void;
+
+    fn wait(&mut self) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<B> Source for B
+where
+    B: ReadBuffer,
+{
+    type Word = <B as ReadBuffer>::Word;
+
+    fn is_peripheral(&self) -> bool {
+        false
+    }
+
+    fn increment(&self) -> Increment {
+        Increment::Width1
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        // Sound, as we only use the length.
+        let (_, len) = unsafe { self.read_buffer() };
+        Some(len)
+    }
+
+    fn start_addr(&mut self) -> *const <B as ReadBuffer>::Word {
+        // Sound, as the DMA controller only reads from the buffer.
+        unsafe { self.read_buffer().0 }
+    }
+}
+
+impl<B> Dest for B
+where
+    B: WriteBuffer,
+{
+    type Word = <B as ReadBuffer>::Word;
+
+    fn is_peripheral(&self) -> bool {
+        false
+    }
+
+    fn increment(&self) -> Increment {
+        Increment::Width1
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        // Sound, as we only use the length.
+        let (_, len) = unsafe { self.read_buffer() };
+        Some(len)
+    }
+
+    fn start_addr(&mut self) -> *mut <B as ReadBuffer>::Word {
+        // Sound, as we own the buffer, and nothing else accesses it while
+        // the transfer is ongoing.
+        unsafe { self.write_buffer().0 }
+    }
+}
+
+/// A memory buffer with a custom address increment
+///
+/// By default, memory buffers are read or written one word after the other.
+/// Wrap a buffer in `Strided` to only read or write every second or fourth
+/// word, or to read or write the first word repeatedly (using
+/// [`Increment::None`]).
+///
+/// [`Increment::None`]: enum.Increment.html#variant.None
+pub struct Strided<B> {
+    buffer: B,
+    increment: Increment,
+}
+
+impl<B> Strided<B> {
+    /// Wrap a buffer, applying the given address increment
+    pub fn new(buffer: B, increment: Increment) -> Self {
+        Strided { buffer, increment }
+    }
+
+    /// Return the wrapped buffer
+    pub fn free(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B> Endpoint for Strided<B> {
+    type Error = Void;
+
+    fn wait(&mut self) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<B> Strided<B>
+where
+    B: ReadBuffer,
+{
+    fn count(&self) -> Option<usize> {
+        // Sound, as we only use the length.
+        let (_, len) = unsafe { self.buffer.read_buffer() };
+        strided_count(len, self.increment)
+    }
+}
+
+fn strided_count(len: usize, increment: Increment) -> Option<usize> {
+    match increment {
+        // If the address isn't incremented, we can read or write the same
+        // word as often as we want.
+        Increment::None if len > 0 => None,
+        Increment::None => Some(0),
+        increment => {
+            let words = increment.words();
+            Some((len + words - 1) / words)
+        }
+    }
+}
+
+impl<B> Source for Strided<B>
+where
+    B: ReadBuffer,
+{
+    type Word = B::Word;
+
+    fn is_peripheral(&self) -> bool {
+        false
+    }
+
+    fn increment(&self) -> Increment {
+        self.increment
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        self.count()
+    }
+
+    fn start_addr(&mut self) -> *const Self::Word {
+        // Sound, as the DMA controller only reads from the buffer.
+        unsafe { self.buffer.read_buffer().0 }
+    }
+}
+
+impl<B> Dest for Strided<B>
+where
+    B: WriteBuffer,
+{
+    type Word = B::Word;
+
+    fn is_peripheral(&self) -> bool {
+        false
+    }
+
+    fn increment(&self) -> Increment {
+        self.increment
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        self.count()
+    }
+
+    fn start_addr(&mut self) -> *mut Self::Word {
+        // Sound, as we own the buffer, and nothing else accesses it while
+        // the transfer is ongoing.
+        unsafe { self.buffer.write_buffer().0 }
+    }
+}
+
+/// A list of memory buffers for scatter-gather transfers
+///
+/// Please refer to [`Channel::start_scatter`] and [`Channel::start_gather`].
+///
+/// [`Channel::start_scatter`]: struct.Channel.html#method.start_scatter
+/// [`Channel::start_gather`]: struct.Channel.html#method.start_gather
+pub struct Segments<B: 'static>(&'static mut [B]);
+
+impl<B> Segments<B> {
+    /// Create a list of segments from a slice of buffers
+    pub fn new(buffers: &'static mut [B]) -> Self {
+        Segments(buffers)
+    }
+
+    /// Return the slice of buffers
+    pub fn free(self) -> &'static mut [B] {
+        self.0
+    }
+}
+
+impl<B> Endpoint for Segments<B>
+where
+    B: Endpoint,
+{
+    type Error = B::Error;
+
+    fn wait(&mut self) -> nb::Result<(), Self::Error> {
+        for buffer in self.0.iter_mut() {
+            buffer.wait()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error that can occur during a DMA transfer
+#[derive(Debug)]
+pub enum Error<S, D> {
+    /// An error occured while waiting for the source to be idle
+    Source(S),
+
+    /// An error occured while waiting for the destination to be idle
+    Dest(D),
+
+    /// The DMA controller reported an error for the transfer
+    ///
+    /// See user manual, section 12.6.9.
+    Controller,
+}
+
+/// A DMA transfer
+pub struct Transfer<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    channel: Channel<T, init_state::Enabled<&'dma Handle>>,
+    source: S,
+    dest: D,
+    count: usize,
+    segments: usize,
+
+    // The index of the active segment, and its transfer count, as last seen
+    progress: Cell<(usize, usize)>,
+}
+
+impl<'dma, T, S, D> Transfer<'dma, T, S, D>
+where
+    T: ChannelTrait,
+    S: Endpoint,
+    D: Endpoint,
+{
+    /// Indicates whether the DMA controller has finished the transfer
+    ///
+    /// This doesn't check whether the source and destination are idle. Use
+    /// [`poll`] for that.
+    ///
+    /// [`poll`]: #method.poll
+    pub fn is_done(&self) -> bool {
+        // The transfer count in XFERCFG is decremented until it wraps around
+        // to 0x3ff, once the last descriptor has been exhausted.
+        // See user manual, section 12.6.18.
+        let active = self.channel.active0.read().act().bits() & T::FLAG != 0;
+        let xfercfg = self.channel.xfercfg.read().bits();
+        self.track_segment(xfercfg);
+
+        !active && xfercount(xfercfg) == 0x3ff
+    }
+
+    /// Checks whether the transfer has finished, without blocking
+    ///
+    /// Returns `WouldBlock`, if the DMA controller hasn't finished the
+    /// transfer, or if the source or destination are not idle yet. Returns an
+    /// error, if the DMA controller reported an error for the transfer, or if
+    /// an error occured in the source or destination.
+    ///
+    /// Once this method has returned `Ok`, [`wait`] will return immediately.
+    /// If it returns an error, the transfer is not stopped. Use [`abort`] to
+    /// stop it and get back the channel, source and destination.
+    ///
+    /// [`wait`]: #method.wait
+    /// [`abort`]: #method.abort
+    pub fn poll(&mut self) -> nb::Result<(), Error<S::Error, D::Error>> {
+        if self.channel.errint0.read().bits() & T::FLAG != 0 {
+            // Sound, as we only write this channel's bit.
+            self.channel.errint0.write(|w| unsafe { w.bits(T::FLAG) });
+            return Err(nb::Error::Other(Error::Controller));
+        }
+
+        if !self.is_done() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        compiler_fence(Ordering::SeqCst);
+
+        self.source.wait().map_err(|err| err.map(Error::Source))?;
+        self.dest.wait().map_err(|err| err.map(Error::Dest))?;
+
+        Ok(())
+    }
+
+    /// Aborts the transfer
+    ///
+    /// Stops the transfer, whether it has finished or not. Returns the
+    /// channel, the source, the destination, and the number of bytes that
+    /// have been transferred.
+    ///
+    /// The DMA controller doesn't indicate which segment of a transfer it's
+    /// working on (see [`Channel::start_transfer`] for how transfers are split
+    /// into segments). The last segment is recognized by its configuration.
+    /// All other segments are counted whenever the transfer is checked, using
+    /// [`is_done`] or [`poll`]. If a transfer consists of more than two
+    /// segments, the number of bytes is only accurate, if the transfer has
+    /// been checked at least once while each segment was active.
+    ///
+    /// [`Channel::start_transfer`]: struct.Channel.html#method.start_transfer
+    /// [`is_done`]: #method.is_done
+    /// [`poll`]: #method.poll
+    pub fn abort(
+        mut self,
+    ) -> (Channel<T, init_state::Enabled<&'dma Handle>>, S, D, usize) {
+        let (active_remaining, xfercfg) = self.channel.abort();
+        let segment = self.track_segment(xfercfg);
+
+        compiler_fence(Ordering::SeqCst);
+
+        // Segment `n` is loaded from linked descriptor `n - 1`. The DMA
+        // controller only reads linked descriptors, so they still contain the
+        // configuration of their segments.
+        let end = cmp::max(self.segments.saturating_sub(1), segment);
+        let later_remaining: usize = self.channel.descriptors[segment..end]
+            .iter()
+            .map(|descriptor| xfercount(descriptor.0.config) + 1)
+            .sum();
+
+        // Saturate, in case the hardware state doesn't match our expectations.
+        let transferred = self
+            .count
+            .saturating_sub(active_remaining + later_remaining);
+
+        // The width of a word in XFERCFG is the binary logarithm of its size
+        // in bytes.
+        let width = (xfercfg >> 8) & 0x3;
+
+        let (channel, source, dest) = self.into_parts();
+        (channel, source, dest, transferred << width)
+    }
+
+    /// Waits for the transfer to finish
+    ///
+    /// If an error occurs, the transfer is aborted, and the error is returned
+    /// together with the channel, the source, and the destination.
+    pub fn wait(
+        mut self,
+    ) -> Result<
+        (Channel<T, init_state::Enabled<&'dma Handle>>, S, D),
+        (
+            Error<S::Error, D::Error>,
+            Channel<T, init_state::Enabled<&'dma Handle>>,
+            S,
+            D,
+        ),
+    > {
+        if let Err(error) = block!(self.poll()) {
+            let (channel, source, dest, _) = self.abort();
+            return Err((error, channel, source, dest));
+        }
+
+        compiler_fence(Ordering::SeqCst);
+
+        Ok(self.into_parts())
+    }
+}
+
+impl<'dma, T, S, D> Transfer<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    fn new(
+        channel: Channel<T, init_state::Enabled<&'dma Handle>>,
+        source: S,
+        dest: D,
+        count: usize,
+        segments: usize,
+    ) -> Self {
+        // The first segment is loaded from the channel descriptor. Its length
+        // is what the linked descriptors leave of the whole transfer.
+        let linked: usize = channel.descriptors[..segments.saturating_sub(1)]
+            .iter()
+            .map(|descriptor| xfercount(descriptor.0.config) + 1)
+            .sum();
+        // An empty transfer has no segments. Its transfer count looks like
+        // that of a finished transfer.
+        let first = (count - linked).wrapping_sub(1) & 0x3ff;
+
+        Transfer {
+            channel,
+            source,
+            dest,
+            count,
+            segments,
+            progress: Cell::new((0, first)),
+        }
+    }
+
+    /// Updates the index of the active segment and returns it
+    ///
+    /// All segments but the last reload the next one once they're exhausted,
+    /// which starts over with a higher transfer count. The last segment
+    /// doesn't reload another one.
+    fn track_segment(&self, xfercfg: u32) -> usize {
+        let (mut segment, last_count) = self.progress.get();
+        let count = xfercount(xfercfg);
+
+        if xfercfg & (0x1 << 1) == 0 {
+            // RELOAD is not set, so this is the last segment.
+            segment = self.segments.saturating_sub(1);
+        } else if count > last_count {
+            segment = cmp::min(segment + 1, self.segments.saturating_sub(2));
+        }
+
+        self.progress.set((segment, count));
+        segment
+    }
+
+    /// Moves the channel, source, and destination out, without dropping
+    fn into_parts(
+        self,
+    ) -> (Channel<T, init_state::Enabled<&'dma Handle>>, S, D) {
+        let this = mem::ManuallyDrop::new(self);
+
+        // Sound, as `this` is never used or dropped after its fields have been
+        // moved out.
+        unsafe {
+            (
+                ptr::read(&this.channel),
+                ptr::read(&this.source),
+                ptr::read(&this.dest),
+            )
+        }
+    }
+}
+
+/// Aborts the transfer, if it is dropped before it has been waited for
+///
+/// Makes sure the DMA controller no longer accesses the source and
+/// destination, before they are dropped.
+impl<'dma, T, S, D> Drop for Transfer<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    fn drop(&mut self) {
+        self.channel.abort();
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// A continuous ping-pong transfer
+///
+/// Fills the two halves of a buffer alternately, until stopped. Created by
+/// [`Channel::start_ping_pong`].
+///
+/// [`Channel::start_ping_pong`]: struct.Channel.html#method.start_ping_pong
+pub struct PingPong<'dma, T, S, B>
+where
+    T: ChannelTrait,
+{
+    channel: Channel<T, init_state::Enabled<&'dma Handle>>,
+    source: S,
+    buffer: B,
+    next: usize,
+}
+
+impl<'dma, T, S, B> PingPong<'dma, T, S, B>
+where
+    T: ChannelTrait,
+    S: Source,
+    B: WriteBuffer<Word = S::Word>,
+{
+    /// Access the half of the buffer that was filled next
+    ///
+    /// Returns `WouldBlock`, if the DMA controller hasn't finished filling
+    /// the next half of the buffer yet. Otherwise, passes that half to `f` and
+    /// returns the result. Each call accesses the other half of the buffer.
+    ///
+    /// Returns [`Overrun`], if the DMA controller started to overwrite the
+    /// half of the buffer before or while `f` accessed it. The data that `f`
+    /// saw might be corrupted in that case.
+    ///
+    /// [`Overrun`]: struct.Overrun.html
+    pub fn peek<F, R>(&mut self, f: F) -> nb::Result<R, Overrun>
+    where
+        F: FnOnce(&[S::Word]) -> R,
+    {
+        let current = self.next;
+        let other = 1 - current;
+
+        if !self.is_filled(current) {
+            return Err(nb::Error::WouldBlock);
+        }
+        if self.is_filled(other) {
+            // Both halves have been filled since we last looked, which means
+            // the DMA controller is already filling the current half again.
+            self.channel.clear_flags();
+            return Err(nb::Error::Other(Overrun));
+        }
+
+        self.clear_filled(current);
+        compiler_fence(Ordering::SeqCst);
+
+        // Sound, as the DMA controller is currently filling the other half,
+        // and we only create a shared reference to this one.
+        let result = unsafe {
+            let (addr, len) = self.buffer.read_buffer();
+            let half = len / 2;
+            f(slice::from_raw_parts(addr.add(current * half), half))
+        };
+
+        compiler_fence(Ordering::SeqCst);
+        self.next = other;
+
+        if self.is_filled(other) {
+            // The other half has been filled while `f` was running, which
+            // means the DMA controller is overwriting the current half.
+            return Err(nb::Error::Other(Overrun));
+        }
+
+        Ok(result)
+    }
+
+    /// Stops the transfer
+    ///
+    /// Returns the channel, the source, and the buffer.
+    pub fn stop(
+        mut self,
+    ) -> (Channel<T, init_state::Enabled<&'dma Handle>>, S, B) {
+        self.channel.abort();
+
+        compiler_fence(Ordering::SeqCst);
+
+        let this = mem::ManuallyDrop::new(self);
+
+        // Sound, as `this` is never used or dropped after its fields have been
+        // moved out.
+        unsafe {
+            (
+                ptr::read(&this.channel),
+                ptr::read(&this.source),
+                ptr::read(&this.buffer),
+            )
+        }
+    }
+
+    fn is_filled(&self, half: usize) -> bool {
+        // The first half sets interrupt flag A when it's filled, the second
+        // half sets interrupt flag B.
+        let flags = if half == 0 {
+            self.channel.inta0.read().bits()
+        } else {
+            self.channel.intb0.read().bits()
+        };
+
+        flags & T::FLAG != 0
+    }
+
+    fn clear_filled(&mut self, half: usize) {
+        // Sound, as we only write this channel's bit.
+        if half == 0 {
+            self.channel.inta0.write(|w| unsafe { w.bits(T::FLAG) });
+        } else {
+            self.channel.intb0.write(|w| unsafe { w.bits(T::FLAG) });
+        }
+    }
+}
+
+/// Stops the transfer, if it is dropped without being stopped
+///
+/// Makes sure the DMA controller no longer accesses the source and buffer,
+/// before they are dropped.
+impl<'dma, T, S, B> Drop for PingPong<'dma, T, S, B>
+where
+    T: ChannelTrait,
+{
+    fn drop(&mut self) {
+        self.channel.abort();
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// Indicates that a ping-pong transfer overwrote data before it was processed
+///
+/// Returned by [`PingPong::peek`].
+///
+/// [`PingPong::peek`]: struct.PingPong.html#method.peek
+#[derive(Debug)]
+pub struct Overrun;
+
+/// A transfer that repeats until stopped
+///
+/// Created by [`Channel::start_repeating`].
+///
+/// [`Channel::start_repeating`]: struct.Channel.html#method.start_repeating
+pub struct Repeating<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    channel: Channel<T, init_state::Enabled<&'dma Handle>>,
+    source: S,
+    dest: D,
+}
+
+impl<'dma, T, S, D> Repeating<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    /// Stops the transfer
+    ///
+    /// Returns the channel, the source, and the destination.
+    pub fn stop(
+        mut self,
+    ) -> (Channel<T, init_state::Enabled<&'dma Handle>>, S, D) {
+        self.channel.abort();
+
+        compiler_fence(Ordering::SeqCst);
+
+        let this = mem::ManuallyDrop::new(self);
+
+        // Sound, as `this` is never used or dropped after its fields have been
+        // moved out.
+        unsafe {
+            (
+                ptr::read(&this.channel),
+                ptr::read(&this.source),
+                ptr::read(&this.dest),
+            )
+        }
+    }
+}
+
+/// Stops the transfer, if it is dropped without being stopped
+///
+/// Makes sure the DMA controller no longer accesses the source and
+/// destination, before they are dropped.
+impl<'dma, T, S, D> Drop for Repeating<'dma, T, S, D>
+where
+    T: ChannelTrait,
+{
+    fn drop(&mut self) {
+        self.channel.abort();
+        compiler_fence(Ordering::SeqCst);
     }
 }
 
 reg!(ACTIVE0, ACTIVE0, pac::DMA0, active0);
+reg!(BUSY0, BUSY0, pac::DMA0, busy0);
 reg!(ENABLESET0, ENABLESET0, pac::DMA0, enableset0);
+reg!(ENABLECLR0, ENABLECLR0, pac::DMA0, enableclr0);
+reg!(INTENSET0, INTENSET0, pac::DMA0, intenset0);
+reg!(INTENCLR0, INTENCLR0, pac::DMA0, intenclr0);
+reg!(INTA0, INTA0, pac::DMA0, inta0);
+reg!(INTB0, INTB0, pac::DMA0, intb0);
+reg!(ERRINT0, ERRINT0, pac::DMA0, errint0);
 reg!(SETTRIG0, SETTRIG0, pac::DMA0, settrig0);
+reg!(ABORT0, ABORT0, pac::DMA0, abort0);
@@ -26,14 +26,31 @@
 //!
 //! Please refer to the [examples in the repository] for more example code.
 //!
+//! # Pattern Generation
+//!
+//! The port registers of the GPIO peripheral can be written by the DMA
+//! controller. [`PortDest`] provides a DMA destination for a group of output
+//! pins, and [`PatternGenerator`] writes buffers of port words into it, one
+//! word per trigger from a timer. This can be used to generate waveforms
+//! without involving the CPU.
+//!
 //! [`swm`]: ../swm/index.html
+//! [`PortDest`]: struct.PortDest.html
+//! [`PatternGenerator`]: struct.PatternGenerator.html
 //! [examples in the repository]: https://github.com/lpc-rs/lpc8xx-hal/tree/master/examples
 
-use embedded_hal::digital::v2::{InputPin, OutputPin, StatefulOutputPin};
+use core::cell::Cell;
+
+use cortex_m::interrupt;
+use embedded_hal::digital::v2::{
+    InputPin, OutputPin, StatefulOutputPin, ToggleableOutputPin,
+};
 use void::Void;
 
 use crate::{
-    init_state, pac,
+    dma, init_state,
+    inputmux::{DmaTrigger, INPUTMUX},
+    pac,
     swm::{pin_state, Pin, PinTrait},
     syscon,
 };
@@ -52,6 +69,7 @@ use crate::{
 /// [module documentation]: index.html
 pub struct GPIO<State = init_state::Enabled> {
     pub(crate) gpio: pac::GPIO,
+    masks_taken: Cell<u8>,
     _state: State,
 }
 
@@ -67,6 +85,7 @@ impl GPIO<init_state::Enabled> {
     pub unsafe fn new_enabled(gpio: pac::GPIO) -> Self {
         GPIO {
             gpio,
+            masks_taken: Cell::new(0),
             _state: init_state::Enabled(()),
         }
     }
@@ -84,6 +103,7 @@ impl GPIO<init_state::Disabled> {
     pub fn new(gpio: pac::GPIO) -> Self {
         GPIO {
             gpio,
+            masks_taken: Cell::new(0),
             _state: init_state::Disabled,
         }
     }
@@ -106,6 +126,7 @@ impl GPIO<init_state::Disabled> {
 
         GPIO {
             gpio: self.gpio,
+            masks_taken: self.masks_taken,
             _state: init_state::Enabled(()),
         }
     }
@@ -131,11 +152,46 @@ impl GPIO<init_state::Enabled> {
 
         GPIO {
             gpio: self.gpio,
+            masks_taken: self.masks_taken,
             _state: init_state::Disabled,
         }
     }
 }
 
+impl GPIO<init_state::Enabled> {
+    /// Take exclusive control over the MASK register of a GPIO port
+    ///
+    /// The MASK register determines which pins are affected by writes to the
+    /// MPIN register. It is required by [`PortGroup`] and [`PortDest`], which
+    /// use MPIN to write multiple pins at once.
+    ///
+    /// Returns `None`, if the [`PortMask`] for this port has already been
+    /// taken, and hasn't been dropped since.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `port` doesn't exist on the target hardware. LPC82x only
+    /// has port 0, LPC845 has ports 0 and 1.
+    ///
+    /// [`PortGroup`]: struct.PortGroup.html
+    /// [`PortDest`]: struct.PortDest.html
+    /// [`PortMask`]: struct.PortMask.html
+    pub fn take_port_mask(&self, port: usize) -> Option<PortMask> {
+        #[cfg(feature = "82x")]
+        assert_eq!(port, 0);
+        #[cfg(feature = "845")]
+        assert!(port < self.gpio.mask.len());
+
+        let taken = self.masks_taken.get();
+        if taken & (0x1 << port) != 0 {
+            return None;
+        }
+        self.masks_taken.set(taken | (0x1 << port));
+
+        Some(PortMask { port, gpio: self })
+    }
+}
+
 impl<State> GPIO<State> {
     /// Return the raw peripheral
     ///
@@ -203,6 +259,36 @@ where
             },
         }
     }
+
+    /// Set pin direction to output, with the output level set to HIGH
+    ///
+    /// Works like [`into_output`], except that the output level is set to HIGH
+    /// before the pin direction is changed. This makes sure the pin never
+    /// drives the wrong level, not even briefly.
+    ///
+    /// [`into_output`]: #method.into_output
+    pub fn into_output_high(
+        self,
+    ) -> Pin<T, pin_state::Gpio<'gpio, direction::Output>> {
+        self.state.registers.set[T::PORT]
+            .write(|w| unsafe { w.setp().bits(T::MASK) });
+        self.into_output()
+    }
+
+    /// Set pin direction to output, with the output level set to LOW
+    ///
+    /// Works like [`into_output`], except that the output level is set to LOW
+    /// before the pin direction is changed. This makes sure the pin never
+    /// drives the wrong level, not even briefly.
+    ///
+    /// [`into_output`]: #method.into_output
+    pub fn into_output_low(
+        self,
+    ) -> Pin<T, pin_state::Gpio<'gpio, direction::Output>> {
+        self.state.registers.clr[T::PORT]
+            .write(|w| unsafe { w.clrp().bits(T::MASK) });
+        self.into_output()
+    }
 }
 
 impl<'gpio, T> OutputPin for Pin<T, pin_state::Gpio<'gpio, direction::Output>>
@@ -288,6 +374,31 @@ where
     }
 }
 
+impl<'gpio, T> ToggleableOutputPin
+    for Pin<T, pin_state::Gpio<'gpio, direction::Output>>
+where
+    T: PinTrait,
+{
+    type Error = Void;
+
+    /// Toggle the pin output
+    ///
+    /// This method is only available, if two conditions are met:
+    /// - The pin is in the GPIO state. Use [`into_gpio_pin`] to achieve this.
+    /// - The pin direction is set to output. See [`into_output`].
+    ///
+    /// Unless both of these conditions are met, code trying to call this method
+    /// will not compile.
+    ///
+    /// [`into_gpio_pin`]: #method.into_gpio_pin
+    /// [`into_output`]: #method.into_output
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.state.registers.not[T::PORT]
+            .write(|w| unsafe { w.notp().bits(T::MASK) });
+        Ok(())
+    }
+}
+
 impl<'gpio, T, D> Pin<T, pin_state::Gpio<'gpio, D>>
 where
     T: PinTrait,
@@ -384,6 +495,598 @@ where
     }
 }
 
+impl<'gpio, T> Pin<T, pin_state::Gpio<'gpio, direction::Output>>
+where
+    T: PinTrait,
+{
+    /// Set the pin output to the given level
+    ///
+    /// Uses the pin's byte pin register (B), which sets the level with a single
+    /// store, regardless of whether it's HIGH or LOW.
+    ///
+    /// See user manual, section 9.6.1.
+    pub fn set_level(&mut self, high: bool) {
+        self.state.registers.b[pin_register_index::<T>()]
+            .write(|w| w.pbyte().bit(high));
+    }
+}
+
+impl<'gpio, T, D> Pin<T, pin_state::Gpio<'gpio, D>>
+where
+    T: PinTrait,
+    D: direction::Direction,
+{
+    /// Read the pin level as a word
+    ///
+    /// Uses the pin's word pin register (W). Returns `0xffffffff`, if the pin
+    /// is HIGH, `0` if it's LOW. This is useful for masking without branches.
+    ///
+    /// See user manual, section 9.6.2.
+    pub fn level_mask(&self) -> u32 {
+        self.state.registers.w[pin_register_index::<T>()]
+            .read()
+            .bits()
+    }
+}
+
+/// The index of a pin's byte and word pin registers (B and W)
+///
+/// The registers of all ports are arranged in a single array. Each port has
+/// room for 32 pins.
+fn pin_register_index<T>() -> usize
+where
+    T: PinTrait,
+{
+    T::PORT * 32 + T::ID as usize
+}
+
+/// Exclusive control over the MASK register of a GPIO port
+///
+/// Required by [`PortGroup`] and [`PortDest`]. Any number of port groups can
+/// share a `PortMask`, as they set MASK right before each write. A
+/// [`PortDest`] needs MASK to stay the same while a DMA transfer is writing
+/// to it, so it takes the `PortMask` by value.
+///
+/// Use [`GPIO::take_port_mask`] to get an instance of this struct. Dropping
+/// it allows it to be taken again.
+///
+/// [`PortGroup`]: struct.PortGroup.html
+/// [`PortDest`]: struct.PortDest.html
+/// [`GPIO::take_port_mask`]: struct.GPIO.html#method.take_port_mask
+pub struct PortMask<'gpio> {
+    port: usize,
+    gpio: &'gpio GPIO,
+}
+
+impl<'gpio> PortMask<'gpio> {
+    /// The port whose MASK register is controlled by this instance
+    pub fn port(&self) -> usize {
+        self.port
+    }
+
+    /// Select the pins that are affected by writes to MPIN
+    fn set_mask(&self, mask: u32) {
+        // A set bit in MASK excludes the pin from writes to MPIN.
+        //
+        // Sound, as all bit patterns are valid for this register.
+        #[cfg(feature = "82x")]
+        self.gpio.gpio.mask0.write(|w| unsafe { w.bits(!mask) });
+        #[cfg(feature = "845")]
+        self.gpio.gpio.mask[self.port].write(|w| unsafe { w.bits(!mask) });
+    }
+
+    /// Write the levels of the pins selected by MASK
+    fn write_mpin(&self, value: u32) {
+        // Sound, as all bit patterns are valid for this register.
+        #[cfg(feature = "82x")]
+        self.gpio.gpio.mpin0.write(|w| unsafe { w.bits(value) });
+        #[cfg(feature = "845")]
+        self.gpio.gpio.mpin[self.port].write(|w| unsafe { w.bits(value) });
+    }
+
+    /// The address of this port's MPIN register
+    fn mpin(&self) -> *mut u32 {
+        #[cfg(feature = "82x")]
+        let mpin = &self.gpio.gpio.mpin0;
+        #[cfg(feature = "845")]
+        let mpin = &self.gpio.gpio.mpin[self.port];
+
+        mpin as *const _ as *mut u32
+    }
+
+    /// Read this port's PIN register
+    fn read_pins(&self) -> u32 {
+        #[cfg(feature = "82x")]
+        let value = self.gpio.gpio.pin0.read().bits();
+        #[cfg(feature = "845")]
+        let value = self.gpio.gpio.pin[self.port].read().bits();
+
+        value
+    }
+}
+
+impl<'gpio> Drop for PortMask<'gpio> {
+    fn drop(&mut self) {
+        let taken = self.gpio.masks_taken.get();
+        self.gpio.masks_taken.set(taken & !(0x1 << self.port));
+    }
+}
+
+/// A group of GPIO output pins, as the destination of a DMA transfer
+///
+/// Each word written by the DMA controller sets the output levels of all pins
+/// in the group at once, using the port's MPIN register. Bits that don't
+/// belong to a pin in the group are ignored, so the transfer can't affect any
+/// pins that are used elsewhere.
+///
+/// Please refer to [`PatternGenerator`] for a way to write to the port at a
+/// fixed rate.
+///
+/// See user manual, sections 9.6.4 and 9.6.6.
+///
+/// [`PatternGenerator`]: struct.PatternGenerator.html
+pub struct PortDest<'gpio, P> {
+    pins: P,
+    mask: PortMask<'gpio>,
+}
+
+impl<'gpio, P> PortDest<'gpio, P>
+where
+    P: PortPins,
+{
+    /// Create a DMA destination from a tuple of output pins
+    ///
+    /// Takes ownership of the pins and the port's [`PortMask`], and writes
+    /// MASK, which stays the same until the `PortDest` is freed.
+    ///
+    /// Fails to compile, if the pins are not all on the same port.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the pins are on a different port than `mask`.
+    ///
+    /// [`PortMask`]: struct.PortMask.html
+    pub fn new(pins: P, mask: PortMask<'gpio>) -> Self {
+        let () = P::ASSERT_SAME_PORT;
+        assert_eq!(P::PORT, mask.port);
+
+        mask.set_mask(P::MASK);
+
+        PortDest { pins, mask }
+    }
+
+    /// Return the pins and the port mask
+    pub fn free(self) -> (P, PortMask<'gpio>) {
+        (self.pins, self.mask)
+    }
+}
+
+impl<'gpio, P> dma::Endpoint for PortDest<'gpio, P> {
+    type Error = Void;
+
+    fn wait(&mut self) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'gpio, P> dma::Dest for PortDest<'gpio, P> {
+    type Word = u32;
+
+    fn is_peripheral(&self) -> bool {
+        // The GPIO peripheral doesn't generate DMA requests. Writes are paced
+        // by the hardware trigger of the channel, if any.
+        false
+    }
+
+    fn increment(&self) -> dma::Increment {
+        dma::Increment::None
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn start_addr(&mut self) -> *mut Self::Word {
+        self.mask.mpin()
+    }
+}
+
+impl<'gpio, P, C> dma::CompatibleWith<C> for PortDest<'gpio, P> where
+    C: dma::ChannelTrait
+{
+}
+
+/// Writes patterns to a GPIO port at a fixed rate, using DMA
+///
+/// Wraps a DMA channel that is configured to transfer one word per hardware
+/// trigger. Route a periodic trigger from a timer to the channel, and each
+/// period, one word from the pattern is written to a group of output pins
+/// (see [`PortDest`]). This can be used for bit-banged protocols, stepper
+/// motor sequences, and the like, without involving the CPU.
+///
+/// Setting up the timer to generate the trigger is up to the user.
+///
+/// [`PortDest`]: struct.PortDest.html
+pub struct PatternGenerator<'dma, T>
+where
+    T: dma::ChannelTrait,
+{
+    channel: dma::Channel<T, init_state::Enabled<&'dma dma::Handle>>,
+}
+
+impl<'dma, T> PatternGenerator<'dma, T>
+where
+    T: dma::ChannelTrait,
+{
+    /// Create a pattern generator
+    ///
+    /// Configures `channel` to transfer one word on each rising edge of
+    /// `trigger`, and routes `trigger` to it using `inputmux`.
+    pub fn new(
+        mut channel: dma::Channel<T, init_state::Enabled<&'dma dma::Handle>>,
+        inputmux: &mut INPUTMUX,
+        trigger: DmaTrigger,
+    ) -> Self {
+        inputmux.route_dma_trigger(&channel, trigger);
+        channel.enable_hardware_trigger(dma::Trigger::rising_edge());
+
+        PatternGenerator { channel }
+    }
+
+    /// Write a pattern to a port once
+    ///
+    /// Writes each word of `pattern` to `dest`, one per trigger. Returns the
+    /// [`dma::Transfer`], which returns the channel, the pattern, and the
+    /// destination once it's complete. The channel remains configured for the
+    /// trigger, and can be passed to [`new`] again.
+    ///
+    /// Please refer to [`dma::Channel::start_transfer`] for details.
+    ///
+    /// [`dma::Transfer`]: ../dma/struct.Transfer.html
+    /// [`new`]: #method.new
+    /// [`dma::Channel::start_transfer`]: ../dma/struct.Channel.html#method.start_transfer
+    pub fn play<'gpio, S, P>(
+        self,
+        pattern: S,
+        dest: PortDest<'gpio, P>,
+    ) -> Result<
+        dma::Transfer<'dma, T, S, PortDest<'gpio, P>>,
+        dma::NotEnoughDescriptors<'dma, T, S, PortDest<'gpio, P>>,
+    >
+    where
+        S: dma::Source<Word = u32> + dma::CompatibleWith<T>,
+    {
+        self.channel.start_transfer(pattern, dest)
+    }
+
+    /// Write a pattern to a port repeatedly
+    ///
+    /// Writes each word of `pattern` to `dest`, one per trigger, starting over
+    /// once the end of the pattern has been reached. Runs until stopped.
+    ///
+    /// Please refer to [`dma::Channel::start_repeating`] for details.
+    ///
+    /// [`dma::Channel::start_repeating`]: ../dma/struct.Channel.html#method.start_repeating
+    pub fn play_repeating<'gpio, S, P>(
+        self,
+        pattern: S,
+        dest: PortDest<'gpio, P>,
+    ) -> Result<
+        dma::Repeating<'dma, T, S, PortDest<'gpio, P>>,
+        dma::NotEnoughDescriptors<'dma, T, S, PortDest<'gpio, P>>,
+    >
+    where
+        S: dma::Source<Word = u32> + dma::CompatibleWith<T>,
+    {
+        self.channel.start_repeating(pattern, dest)
+    }
+
+    /// Return the DMA channel
+    ///
+    /// Disables hardware triggering for the channel before returning it.
+    pub fn free(
+        mut self,
+    ) -> dma::Channel<T, init_state::Enabled<&'dma dma::Handle>> {
+        self.channel.disable_hardware_trigger();
+        self.channel
+    }
+}
+
+impl<'gpio, T, D> Pin<T, pin_state::Gpio<'gpio, D>>
+where
+    T: PinTrait,
+    D: direction::Direction,
+{
+    /// Erase the pin type
+    ///
+    /// Returns a [`DynamicPin`], which stores the pin's port and mask at
+    /// runtime, instead of encoding them in its type. This allows pins to be
+    /// stored in arrays, or to be selected based on runtime values.
+    ///
+    /// The returned pin stays in the GPIO state and keeps its direction, so
+    /// the same methods are available as before.
+    ///
+    /// # Example
+    ///
+    /// ``` no_run
+    /// use lpc82x_hal::prelude::*;
+    /// use lpc82x_hal::Peripherals;
+    ///
+    /// let p = Peripherals::take().unwrap();
+    ///
+    /// let swm = p.SWM.split();
+    ///
+    /// let mut leds = [
+    ///     swm.pins.pio0_12.into_gpio_pin(&p.GPIO).into_output().downgrade(),
+    ///     swm.pins.pio0_13.into_gpio_pin(&p.GPIO).into_output().downgrade(),
+    /// ];
+    ///
+    /// for led in &mut leds {
+    ///     led.set_high();
+    /// }
+    /// ```
+    ///
+    /// [`DynamicPin`]: struct.DynamicPin.html
+    pub fn downgrade(self) -> DynamicPin<'gpio, D> {
+        DynamicPin {
+            port: T::PORT,
+            mask: T::MASK,
+            registers: self.state.registers,
+            _direction: self.state._direction,
+        }
+    }
+}
+
+/// A GPIO pin whose identity is only known at runtime
+///
+/// Unlike [`Pin`], which has a distinct type for each physical pin, all
+/// `DynamicPin`s with the same direction have the same type. You can get one
+/// by calling [`Pin::downgrade`] on a pin that is in the GPIO state.
+///
+/// [`Pin`]: ../swm/struct.Pin.html
+/// [`Pin::downgrade`]: ../swm/struct.Pin.html#method.downgrade
+pub struct DynamicPin<'gpio, D> {
+    port: usize,
+    mask: u32,
+    registers: pin_state::GpioRegisters<'gpio>,
+    _direction: D,
+}
+
+impl<'gpio, D> DynamicPin<'gpio, D> {
+    /// The port the pin belongs to
+    pub fn port(&self) -> usize {
+        self.port
+    }
+
+    /// The bit that the pin occupies in its port's registers
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    fn is_pin_high(&self) -> bool {
+        self.registers.pin[self.port].read().port().bits() & self.mask
+            == self.mask
+    }
+}
+
+impl<'gpio> OutputPin for DynamicPin<'gpio, direction::Output> {
+    type Error = Void;
+
+    /// Set the pin output to HIGH
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let mask = self.mask;
+        self.registers.set[self.port].write(|w| unsafe { w.setp().bits(mask) });
+        Ok(())
+    }
+
+    /// Set the pin output to LOW
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let mask = self.mask;
+        self.registers.clr[self.port].write(|w| unsafe { w.clrp().bits(mask) });
+        Ok(())
+    }
+}
+
+impl<'gpio> StatefulOutputPin for DynamicPin<'gpio, direction::Output> {
+    /// Indicates whether the pin output is currently set to HIGH
+    fn is_set_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.is_pin_high())
+    }
+
+    /// Indicates whether the pin output is currently set to LOW
+    fn is_set_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.is_pin_high())
+    }
+}
+
+impl<'gpio> ToggleableOutputPin for DynamicPin<'gpio, direction::Output> {
+    type Error = Void;
+
+    /// Toggle the pin output
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        let mask = self.mask;
+        self.registers.not[self.port].write(|w| unsafe { w.notp().bits(mask) });
+        Ok(())
+    }
+}
+
+impl<'gpio> InputPin for DynamicPin<'gpio, direction::Input> {
+    type Error = Void;
+
+    /// Indicates wether the pin input is HIGH
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.is_pin_high())
+    }
+
+    /// Indicates wether the pin input is LOW
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.is_pin_high())
+    }
+}
+
+/// A group of GPIO output pins on the same port that are accessed together
+///
+/// Takes ownership of a number of output pins, and writes or reads all of them
+/// in a single register access, using the port's MASK and MPIN registers. This
+/// is useful for parallel buses, where writing the pins one by one would
+/// cause glitches.
+///
+/// Values written and read use the pins' bit positions within the port. Use
+/// [`PortPins::MASK`] to find out which bits those are.
+///
+/// The MASK register is shared by all users of MPIN on the same port, which
+/// is why a `PortGroup` borrows the port's [`PortMask`]. `PortGroup` sets
+/// MASK right before each write, in a critical section, so multiple port
+/// groups can share a [`PortMask`]. A [`PortDest`] on the same port can't
+/// exist at the same time, as it needs the [`PortMask`] by value.
+///
+/// See user manual, sections 9.6.4 and 9.6.6.
+///
+/// # Example
+///
+/// ``` no_run
+/// use lpc8xx_hal::{gpio::PortGroup, Peripherals};
+///
+/// let p = Peripherals::take().unwrap();
+///
+/// let swm = p.SWM.split();
+///
+/// let d0 = swm.pins.pio0_12.into_gpio_pin(&p.GPIO).into_output();
+/// let d1 = swm.pins.pio0_13.into_gpio_pin(&p.GPIO).into_output();
+/// let d2 = swm.pins.pio0_14.into_gpio_pin(&p.GPIO).into_output();
+///
+/// let mask = p.GPIO.take_port_mask(0).unwrap();
+/// let mut bus = PortGroup::new((d0, d1, d2), &mask);
+///
+/// // Sets PIO0_12 and PIO0_14 to HIGH, PIO0_13 to LOW
+/// bus.write(0b101 << 12);
+/// ```
+///
+/// [`PortPins::MASK`]: trait.PortPins.html#associatedconstant.MASK
+/// [`PortMask`]: struct.PortMask.html
+/// [`PortDest`]: struct.PortDest.html
+pub struct PortGroup<'gpio, P> {
+    pins: P,
+    mask: &'gpio PortMask<'gpio>,
+}
+
+impl<'gpio, P> PortGroup<'gpio, P>
+where
+    P: PortPins,
+{
+    /// Create a port group from a tuple of output pins
+    ///
+    /// Fails to compile, if the pins are not all on the same port.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the pins are on a different port than `mask`.
+    pub fn new(pins: P, mask: &'gpio PortMask<'gpio>) -> Self {
+        let () = P::ASSERT_SAME_PORT;
+        assert_eq!(P::PORT, mask.port);
+
+        PortGroup { pins, mask }
+    }
+
+    /// Set the output levels of all pins in the group
+    ///
+    /// Each pin is set to HIGH or LOW, depending on its bit in `value`. Bits
+    /// that don't belong to a pin in the group are ignored.
+    pub fn write(&mut self, value: u32) {
+        // MASK is shared, so we need to make sure nobody changes it between
+        // our writes.
+        interrupt::free(|_| {
+            self.mask.set_mask(P::MASK);
+
+            self.mask.write_mpin(value);
+        });
+    }
+
+    /// Read the levels of all pins in the group
+    ///
+    /// Bits that don't belong to a pin in the group are zero.
+    pub fn read(&self) -> u32 {
+        self.mask.read_pins() & P::MASK
+    }
+
+    /// Return the pins
+    pub fn free(self) -> P {
+        self.pins
+    }
+}
+
+/// Implemented for output pins and tuples of output pins
+///
+/// Used by [`PortGroup`]. Implemented for GPIO pins in the output state, and
+/// for tuples of up to twelve types that implement this trait themselves.
+///
+/// [`PortGroup`]: struct.PortGroup.html
+pub trait PortPins {
+    /// The port of the pins
+    ///
+    /// If the pins are on different ports, this is the port of the first one.
+    const PORT: usize;
+
+    /// The bits that the pins occupy in the port's registers
+    const MASK: u32;
+
+    /// Indicates whether all pins are on the same port
+    const SAME_PORT: bool;
+}
+
+impl<'gpio, T> PortPins for Pin<T, pin_state::Gpio<'gpio, direction::Output>>
+where
+    T: PinTrait,
+{
+    const PORT: usize = T::PORT;
+    const MASK: u32 = T::MASK;
+    const SAME_PORT: bool = true;
+}
+
+macro_rules! port_pins_tuple {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first $(, $rest)*> PortPins for ($first, $($rest,)*)
+        where
+            $first: PortPins,
+            $($rest: PortPins,)*
+        {
+            const PORT: usize = $first::PORT;
+            const MASK: u32 = $first::MASK $(| $rest::MASK)*;
+            const SAME_PORT: bool = $first::SAME_PORT
+                $(&& $rest::SAME_PORT && $rest::PORT == $first::PORT)*;
+        }
+    };
+}
+
+port_pins_tuple!(A);
+port_pins_tuple!(A, B);
+port_pins_tuple!(A, B, C);
+port_pins_tuple!(A, B, C, D);
+port_pins_tuple!(A, B, C, D, E);
+port_pins_tuple!(A, B, C, D, E, F);
+port_pins_tuple!(A, B, C, D, E, F, G);
+port_pins_tuple!(A, B, C, D, E, F, G, H);
+port_pins_tuple!(A, B, C, D, E, F, G, H, I);
+port_pins_tuple!(A, B, C, D, E, F, G, H, I, J);
+port_pins_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+port_pins_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Checks [`PortPins::SAME_PORT`] at compile time
+///
+/// [`PortPins::SAME_PORT`]: trait.PortPins.html#associatedconstant.SAME_PORT
+trait AssertSamePort {
+    const ASSERT_SAME_PORT: ();
+}
+
+impl<P> AssertSamePort for P
+where
+    P: PortPins,
+{
+    // Indexing out of bounds fails constant evaluation, if the pins are not
+    // all on the same port.
+    const ASSERT_SAME_PORT: () = [()][!P::SAME_PORT as usize];
+}
+
 /// Contains types to indicate the direction of GPIO pins
 ///
 /// Please refer to [`Pin`] for documentation on how these types are used.
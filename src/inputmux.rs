@@ -0,0 +1,153 @@
+//! API for input multiplexing (INPUTMUX)
+//!
+//! The entry point to this API is [`INPUTMUX`]. Currently, only the routing of
+//! DMA trigger inputs, and of DMA trigger outputs back into the DMA trigger
+//! inputs, is supported.
+//!
+//! The input multiplexing is described in the user manual, chapter 11.
+
+use crate::{dma, pac};
+
+/// Interface to input multiplexing (INPUTMUX)
+///
+/// Use [`Peripherals`] to gain access to an instance of this struct.
+///
+/// Please refer to the [module documentation] for more information.
+///
+/// [`Peripherals`]: ../struct.Peripherals.html
+/// [module documentation]: index.html
+pub struct INPUTMUX {
+    inputmux: pac::INPUTMUX,
+}
+
+impl INPUTMUX {
+    pub(crate) fn new(inputmux: pac::INPUTMUX) -> Self {
+        INPUTMUX { inputmux }
+    }
+
+    /// Route a DMA trigger source to a DMA channel
+    ///
+    /// The trigger only takes effect, if hardware triggering has been enabled
+    /// for the channel. See [`dma::Channel::enable_hardware_trigger`].
+    ///
+    /// See user manual, section 11.6.3.
+    ///
+    /// [`dma::Channel::enable_hardware_trigger`]: ../dma/struct.Channel.html#method.enable_hardware_trigger
+    pub fn route_dma_trigger<T, S>(
+        &mut self,
+        _channel: &dma::Channel<T, S>,
+        source: DmaTrigger,
+    ) where
+        T: dma::ChannelTrait,
+    {
+        // Sound, as all values of `DmaTrigger` are valid trigger inputs.
+        self.inputmux.dma_itrig_inmux[T::INDEX]
+            .write(|w| unsafe { w.bits(source as u32) });
+    }
+
+    /// Route the trigger output of a DMA channel to a DMA trigger input
+    ///
+    /// The trigger output of the channel becomes available as
+    /// [`DmaTrigger::DmaInmux0`] or [`DmaTrigger::DmaInmux1`], depending on
+    /// `inmux`. This allows the completion of one channel's transfer to
+    /// trigger the transfer of another channel.
+    ///
+    /// See user manual, section 11.6.1.
+    ///
+    /// [`DmaTrigger::DmaInmux0`]: enum.DmaTrigger.html#variant.DmaInmux0
+    /// [`DmaTrigger::DmaInmux1`]: enum.DmaTrigger.html#variant.DmaInmux1
+    pub fn route_dma_output<T, S>(
+        &mut self,
+        _channel: &dma::Channel<T, S>,
+        inmux: DmaInmux,
+    ) where
+        T: dma::ChannelTrait,
+    {
+        // Sound, as all DMA channel indices are valid values.
+        self.inputmux.dma_inmux_inmux[inmux as usize]
+            .write(|w| unsafe { w.bits(T::INDEX as u32) });
+    }
+
+    /// Return the raw peripheral
+    ///
+    /// This method serves as an escape hatch from the HAL API. It returns the
+    /// raw peripheral, allowing you to do whatever you want with it, without
+    /// limitations imposed by the API.
+    ///
+    /// If you are using this method because a feature you need is missing from
+    /// the HAL API, please [open an issue] or, if an issue for your feature
+    /// request already exists, comment on the existing issue, so we can
+    /// prioritize it accordingly.
+    ///
+    /// [open an issue]: https://github.com/lpc-rs/lpc8xx-hal/issues
+    pub fn free(self) -> pac::INPUTMUX {
+        self.inputmux
+    }
+}
+
+/// A source that can trigger DMA transfers
+///
+/// See user manual, section 11.6.3.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DmaTrigger {
+    /// ADC0 sequence A interrupt
+    Adc0SeqA = 0,
+
+    /// ADC0 sequence B interrupt
+    Adc0SeqB = 1,
+
+    /// SCT0 DMA request 0
+    Sct0Dma0 = 2,
+
+    /// SCT0 DMA request 1
+    Sct0Dma1 = 3,
+
+    /// Analog comparator output
+    AcmpO = 4,
+
+    /// Pin interrupt 4
+    PinInt4 = 5,
+
+    /// Pin interrupt 5
+    PinInt5 = 6,
+
+    /// DMA trigger output, as selected by DMA_INMUX_INMUX0
+    ///
+    /// See [`INPUTMUX::route_dma_output`].
+    ///
+    /// [`INPUTMUX::route_dma_output`]: struct.INPUTMUX.html#method.route_dma_output
+    DmaInmux0 = 7,
+
+    /// DMA trigger output, as selected by DMA_INMUX_INMUX1
+    ///
+    /// See [`INPUTMUX::route_dma_output`].
+    ///
+    /// [`INPUTMUX::route_dma_output`]: struct.INPUTMUX.html#method.route_dma_output
+    DmaInmux1 = 8,
+
+    /// CTIMER0 match 0 DMA request
+    #[cfg(feature = "845")]
+    Ctimer0Match0 = 9,
+
+    /// CTIMER0 match 1 DMA request
+    #[cfg(feature = "845")]
+    Ctimer0Match1 = 10,
+}
+
+/// Selects one of the two DMA trigger output multiplexers
+///
+/// Used by [`INPUTMUX::route_dma_output`].
+///
+/// [`INPUTMUX::route_dma_output`]: struct.INPUTMUX.html#method.route_dma_output
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DmaInmux {
+    /// DMA_INMUX_INMUX0, available as [`DmaTrigger::DmaInmux0`]
+    ///
+    /// [`DmaTrigger::DmaInmux0`]: enum.DmaTrigger.html#variant.DmaInmux0
+    Inmux0 = 0,
+
+    /// DMA_INMUX_INMUX1, available as [`DmaTrigger::DmaInmux1`]
+    ///
+    /// [`DmaTrigger::DmaInmux1`]: enum.DmaTrigger.html#variant.DmaInmux1
+    Inmux1 = 1,
+}
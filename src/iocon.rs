@@ -0,0 +1,547 @@
+//! API for I/O configuration (IOCON)
+//!
+//! The entry point to this API is [`IOCON`]. It needs to be enabled before it
+//! can be used to configure the electrical properties of pins: pull-up and
+//! pull-down resistors, repeater mode, hysteresis, input inversion, and
+//! pseudo open-drain mode. It also controls the digital input filters, which
+//! can be used to reject glitches, for example to debounce buttons.
+//!
+//! The configuration is applied using methods of [`Pin`], which takes a
+//! reference to [`IOCON`] as proof that the peripheral is enabled.
+//!
+//! The IOCON peripheral is described in the user manual, chapter 8.
+//!
+//! # Examples
+//!
+//! Configure a GPIO pin as an input with pull-up resistor:
+//!
+//! ``` no_run
+//! use lpc8xx_hal::prelude::*;
+//! use lpc8xx_hal::Peripherals;
+//!
+//! let p = Peripherals::take().unwrap();
+//!
+//! let mut syscon = p.SYSCON.split();
+//! let swm = p.SWM.split();
+//!
+//! #[cfg(feature = "82x")]
+//! let gpio = p.GPIO;
+//! #[cfg(feature = "845")]
+//! let gpio = p.GPIO.enable(&mut syscon.handle);
+//!
+//! let iocon = p.IOCON.enable(&mut syscon.handle);
+//!
+//! let button = swm.pins.pio0_4
+//!     .into_gpio_pin(&gpio)
+//!     .into_pull_up_input(&iocon);
+//!
+//! if button.is_low().unwrap() {
+//!     // The button is pressed
+//! }
+//! ```
+//!
+//! [`Pin`]: ../swm/struct.Pin.html
+
+use crate::{
+    gpio::direction,
+    init_state, pac,
+    swm::{self, pin_state, Pin, PinTrait},
+    syscon,
+};
+
+/// Entry point to the I/O configuration API
+///
+/// Controls the clock of the IOCON peripheral. An enabled instance is required
+/// by the methods of [`Pin`] that change a pin's electrical configuration.
+///
+/// Use [`Peripherals`] to gain access to an instance of this struct.
+///
+/// Please refer to the [module documentation] for more information.
+///
+/// [`Pin`]: ../swm/struct.Pin.html
+/// [`Peripherals`]: ../struct.Peripherals.html
+/// [module documentation]: index.html
+pub struct IOCON<State = init_state::Enabled> {
+    iocon: pac::IOCON,
+    _state: State,
+}
+
+impl IOCON<init_state::Disabled> {
+    /// Create a disabled IOCON peripheral
+    ///
+    /// This method creates an `IOCON` instance that it assumes is in the
+    /// [`Disabled`] state. As it's only possible to enable a [`Disabled`]
+    /// `IOCON` instance, it's also safe to pass an already [`Enabled`]
+    /// instance.
+    ///
+    /// [`Disabled`]: ../init_state/struct.Disabled.html
+    /// [`Enabled`]: ../init_state/struct.Enabled.html
+    pub fn new(iocon: pac::IOCON) -> Self {
+        IOCON {
+            iocon,
+            _state: init_state::Disabled,
+        }
+    }
+
+    /// Enable the IOCON peripheral
+    ///
+    /// This method is only available, if `IOCON` is in the [`Disabled`] state.
+    /// Code that attempts to call this method when the peripheral is already
+    /// enabled will not compile.
+    ///
+    /// Consumes this instance of `IOCON` and returns another instance that has
+    /// its `State` type parameter set to [`Enabled`].
+    ///
+    /// [`Disabled`]: ../init_state/struct.Disabled.html
+    /// [`Enabled`]: ../init_state/struct.Enabled.html
+    pub fn enable(
+        self,
+        syscon: &mut syscon::Handle,
+    ) -> IOCON<init_state::Enabled> {
+        syscon.enable_clock(&self.iocon);
+
+        IOCON {
+            iocon: self.iocon,
+            _state: init_state::Enabled(()),
+        }
+    }
+}
+
+impl IOCON<init_state::Enabled> {
+    /// Disable the IOCON peripheral
+    ///
+    /// This method is only available, if `IOCON` is in the [`Enabled`] state.
+    /// Code that attempts to call this method when the peripheral is already
+    /// disabled will not compile.
+    ///
+    /// Pin configuration that has already been applied stays in effect while
+    /// the peripheral is disabled.
+    ///
+    /// Consumes this instance of `IOCON` and returns another instance that has
+    /// its `State` type parameter set to [`Disabled`].
+    ///
+    /// [`Enabled`]: ../init_state/struct.Enabled.html
+    /// [`Disabled`]: ../init_state/struct.Disabled.html
+    pub fn disable(
+        self,
+        syscon: &mut syscon::Handle,
+    ) -> IOCON<init_state::Disabled> {
+        syscon.disable_clock(&self.iocon);
+
+        IOCON {
+            iocon: self.iocon,
+            _state: init_state::Disabled,
+        }
+    }
+}
+
+impl<State> IOCON<State> {
+    /// Return the raw peripheral
+    ///
+    /// This method serves as an escape hatch from the HAL API. It returns the
+    /// raw peripheral, allowing you to do whatever you want with it, without
+    /// limitations imposed by the API.
+    ///
+    /// If you are using this method because a feature you need is missing from
+    /// the HAL API, please [open an issue] or, if an issue for your feature
+    /// request already exists, comment on the existing issue, so we can
+    /// prioritize it accordingly.
+    ///
+    /// [open an issue]: https://github.com/lpc-rs/lpc8xx-hal/issues
+    pub fn free(self) -> pac::IOCON {
+        self.iocon
+    }
+}
+
+impl<T, S> Pin<T, S>
+where
+    T: IoconPin,
+    S: pin_state::PinState,
+{
+    /// Enable or disable input inversion
+    ///
+    /// If enabled, the input level seen by the peripherals connected to this
+    /// pin is the inverse of the actual level.
+    ///
+    /// See user manual, section 8.4.1.
+    pub fn set_inverted(&mut self, inverted: bool, iocon: &IOCON) {
+        T::modify(&iocon.iocon, INV, if inverted { INV } else { 0 });
+    }
+
+    /// Enable the digital input filter
+    ///
+    /// The filter rejects input pulses that are shorter than the given number
+    /// of cycles of the filter clock. The filter clock is provided by one of
+    /// the IOCON clock dividers in [`syscon::Parts`], which needs to be
+    /// configured with a non-zero divider value.
+    ///
+    /// See user manual, section 8.3.5.
+    ///
+    /// [`syscon::Parts`]: ../syscon/struct.Parts.html
+    pub fn set_input_filter<C>(
+        &mut self,
+        filter: Filter,
+        _clock: &C,
+        iocon: &IOCON,
+    ) where
+        C: syscon::FilterClock,
+    {
+        let value = ((filter as u32) << S_MODE_OFFSET)
+            | ((C::INDEX as u32) << CLK_DIV_OFFSET);
+        T::modify(&iocon.iocon, S_MODE | CLK_DIV, value);
+    }
+
+    /// Disable the digital input filter
+    ///
+    /// This is the reset default.
+    pub fn disable_input_filter(&mut self, iocon: &IOCON) {
+        T::modify(&iocon.iocon, S_MODE, 0);
+    }
+}
+
+impl<T, S> Pin<T, S>
+where
+    T: StandardPin,
+    S: pin_state::PinState,
+{
+    /// Select the on-chip pull-up/pull-down resistor mode
+    ///
+    /// See user manual, section 8.4.1.
+    pub fn set_mode(&mut self, mode: Mode, iocon: &IOCON) {
+        T::modify(&iocon.iocon, MODE, (mode as u32) << MODE_OFFSET);
+    }
+
+    /// Enable or disable input hysteresis
+    ///
+    /// See user manual, section 8.4.1.
+    pub fn set_hysteresis(&mut self, hysteresis: bool, iocon: &IOCON) {
+        T::modify(&iocon.iocon, HYS, if hysteresis { HYS } else { 0 });
+    }
+
+    /// Enable or disable pseudo open-drain mode
+    ///
+    /// In open-drain mode, the pin only drives LOW. If set to HIGH, it is left
+    /// floating, and an internal or external pull-up resistor can pull it up.
+    ///
+    /// See user manual, section 8.4.1.
+    pub fn set_open_drain(&mut self, open_drain: bool, iocon: &IOCON) {
+        T::modify(&iocon.iocon, OD, if open_drain { OD } else { 0 });
+    }
+}
+
+impl<'gpio, T, D> Pin<T, pin_state::Gpio<'gpio, D>>
+where
+    T: StandardPin,
+    D: direction::NotInput,
+{
+    /// Set pin direction to input, with the pull-up resistor enabled
+    ///
+    /// Works like [`into_input`], but enables the pin's pull-up resistor before
+    /// the pin direction is changed.
+    ///
+    /// [`into_input`]: #method.into_input
+    pub fn into_pull_up_input(
+        mut self,
+        iocon: &IOCON,
+    ) -> Pin<T, pin_state::Gpio<'gpio, direction::Input>> {
+        self.set_mode(Mode::PullUp, iocon);
+        self.into_input()
+    }
+
+    /// Set pin direction to input, with the pull-down resistor enabled
+    ///
+    /// Works like [`into_input`], but enables the pin's pull-down resistor
+    /// before the pin direction is changed.
+    ///
+    /// [`into_input`]: #method.into_input
+    pub fn into_pull_down_input(
+        mut self,
+        iocon: &IOCON,
+    ) -> Pin<T, pin_state::Gpio<'gpio, direction::Input>> {
+        self.set_mode(Mode::PullDown, iocon);
+        self.into_input()
+    }
+}
+
+impl<'gpio, T, D> Pin<T, pin_state::Gpio<'gpio, D>>
+where
+    T: StandardPin,
+    D: direction::NotOutput,
+{
+    /// Set pin direction to output, in pseudo open-drain mode
+    ///
+    /// Works like [`into_output`], but enables open-drain mode before the pin
+    /// direction is changed. The pull resistors are disabled, so an external
+    /// pull-up is required. Use [`set_mode`] to change that.
+    ///
+    /// [`into_output`]: #method.into_output
+    /// [`set_mode`]: #method.set_mode
+    pub fn into_open_drain_output(
+        mut self,
+        iocon: &IOCON,
+    ) -> Pin<T, pin_state::Gpio<'gpio, direction::Output>> {
+        self.set_mode(Mode::Inactive, iocon);
+        self.set_open_drain(true, iocon);
+        self.into_output()
+    }
+}
+
+/// The resistor mode of a pin
+///
+/// Used with [`Pin::set_mode`].
+///
+/// [`Pin::set_mode`]: ../swm/struct.Pin.html#method.set_mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// No pull-up or pull-down resistor enabled
+    Inactive = 0b00,
+
+    /// Pull-down resistor enabled
+    PullDown = 0b01,
+
+    /// Pull-up resistor enabled
+    ///
+    /// This is the reset default for most pins.
+    PullUp = 0b10,
+
+    /// Repeater mode
+    ///
+    /// Enables the pull-up resistor if the pin is HIGH, and the pull-down
+    /// resistor if it's LOW. This keeps the pin at its last known level, if it
+    /// isn't driven externally.
+    Repeater = 0b11,
+}
+
+/// The number of filter clock cycles used by the digital input filter
+///
+/// Used with [`Pin::set_input_filter`]. Input pulses that are shorter than the
+/// selected number of cycles are rejected.
+///
+/// [`Pin::set_input_filter`]: ../swm/struct.Pin.html#method.set_input_filter
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Filter {
+    /// Reject pulses shorter than one filter clock cycle
+    OneClock = 0b01,
+
+    /// Reject pulses shorter than two filter clock cycles
+    TwoClocks = 0b10,
+
+    /// Reject pulses shorter than three filter clock cycles
+    ThreeClocks = 0b11,
+}
+
+const MODE_OFFSET: u32 = 3;
+const MODE: u32 = 0b11 << MODE_OFFSET;
+const HYS: u32 = 0x1 << 5;
+const INV: u32 = 0x1 << 6;
+const OD: u32 = 0x1 << 10;
+const S_MODE_OFFSET: u32 = 11;
+const S_MODE: u32 = 0b11 << S_MODE_OFFSET;
+const CLK_DIV_OFFSET: u32 = 13;
+const CLK_DIV: u32 = 0b111 << CLK_DIV_OFFSET;
+
+/// Implemented by pins that can be configured using IOCON
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+///
+/// Please refer to [`Pin`] for the public API used to configure pins.
+///
+/// [`Pin`]: ../swm/struct.Pin.html
+pub trait IoconPin: PinTrait {
+    /// Modify the bits selected by `mask` in the pin's IOCON register
+    fn modify(iocon: &pac::iocon::RegisterBlock, mask: u32, value: u32);
+}
+
+/// Implemented by pins that have a standard IOCON register
+///
+/// All pins implement this trait, except for the true open-drain pins used for
+/// Fast-mode Plus I2C (PIO0_10 and PIO0_11). Those don't have pull-up or
+/// pull-down resistors, hysteresis, or a pseudo open-drain mode.
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+pub trait StandardPin: IoconPin {}
+
+macro_rules! iocon_pins {
+    ($($(#[$attr:meta])* $pin:ident, $field:ident;)*) => {
+        $(
+            $(#[$attr])*
+            impl IoconPin for swm::$pin {
+                fn modify(
+                    iocon: &pac::iocon::RegisterBlock,
+                    mask: u32,
+                    value: u32,
+                ) {
+                    // Sound, as the register belongs to a single pin, which
+                    // is owned by the caller, and all values are valid.
+                    iocon.$field.modify(|r, w| unsafe {
+                        w.bits((r.bits() & !mask) | (value & mask))
+                    });
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! standard_pins {
+    ($($pin:ident),* $(,)*) => {
+        $(
+            impl StandardPin for swm::$pin {}
+        )*
+    };
+}
+
+#[cfg(feature = "82x")]
+iocon_pins!(
+    #[cfg(not(feature = "20"))]
+    PIO0_17, pio0_17;
+    PIO0_13, pio0_13;
+    PIO0_12, pio0_12;
+    PIO0_5, pio0_5;
+    PIO0_4, pio0_4;
+    PIO0_3, pio0_3;
+    PIO0_2, pio0_2;
+    PIO0_11, pio0_11;
+    PIO0_10, pio0_10;
+    #[cfg(not(feature = "20"))]
+    PIO0_16, pio0_16;
+    PIO0_15, pio0_15;
+    PIO0_1, pio0_1;
+    PIO0_9, pio0_9;
+    PIO0_8, pio0_8;
+    PIO0_7, pio0_7;
+    PIO0_6, pio0_6;
+    PIO0_0, pio0_0;
+    PIO0_14, pio0_14;
+    #[cfg(not(feature = "20"))]
+    PIO0_28, pio0_28;
+    #[cfg(not(feature = "20"))]
+    PIO0_27, pio0_27;
+    #[cfg(not(feature = "20"))]
+    PIO0_26, pio0_26;
+    #[cfg(not(feature = "20"))]
+    PIO0_25, pio0_25;
+    #[cfg(not(feature = "20"))]
+    PIO0_24, pio0_24;
+    #[cfg(not(feature = "20"))]
+    PIO0_23, pio0_23;
+    #[cfg(not(feature = "20"))]
+    PIO0_22, pio0_22;
+    #[cfg(not(feature = "20"))]
+    PIO0_21, pio0_21;
+    #[cfg(not(feature = "20"))]
+    PIO0_20, pio0_20;
+    #[cfg(not(feature = "20"))]
+    PIO0_19, pio0_19;
+    #[cfg(not(feature = "20"))]
+    PIO0_18, pio0_18;
+);
+
+#[cfg(feature = "845")]
+iocon_pins!(
+    PIO0_17, pio0_17;
+    PIO0_13, pio0_13;
+    PIO0_12, pio0_12;
+    PIO0_5, pio0_5;
+    PIO0_4, pio0_4;
+    PIO0_3, pio0_3;
+    PIO0_2, pio0_2;
+    PIO0_11, pio0_11;
+    PIO0_10, pio0_10;
+    PIO0_16, pio0_16;
+    PIO0_15, pio0_15;
+    PIO0_1, pio0_1;
+    PIO0_9, pio0_9;
+    PIO0_8, pio0_8;
+    PIO0_7, pio0_7;
+    PIO0_6, pio0_6;
+    PIO0_0, pio0_0;
+    PIO0_14, pio0_14;
+    PIO0_28, pio0_28;
+    PIO0_27, pio0_27;
+    PIO0_26, pio0_26;
+    PIO0_25, pio0_25;
+    PIO0_24, pio0_24;
+    PIO0_23, pio0_23;
+    PIO0_22, pio0_22;
+    PIO0_21, pio0_21;
+    PIO0_20, pio0_20;
+    PIO0_19, pio0_19;
+    PIO0_18, pio0_18;
+    #[cfg(not(feature = "33"))]
+    PIO1_8, pio1_8;
+    #[cfg(not(feature = "33"))]
+    PIO1_9, pio1_9;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_12, pio1_12;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_13, pio1_13;
+    #[cfg(not(feature = "33"))]
+    PIO0_31, pio0_31;
+    #[cfg(not(feature = "33"))]
+    PIO1_0, pio1_0;
+    #[cfg(not(feature = "33"))]
+    PIO1_1, pio1_1;
+    #[cfg(not(feature = "33"))]
+    PIO1_2, pio1_2;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_14, pio1_14;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_15, pio1_15;
+    #[cfg(not(feature = "33"))]
+    PIO1_3, pio1_3;
+    #[cfg(not(feature = "33"))]
+    PIO1_4, pio1_4;
+    #[cfg(not(feature = "33"))]
+    PIO1_5, pio1_5;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_16, pio1_16;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_17, pio1_17;
+    #[cfg(not(feature = "33"))]
+    PIO1_6, pio1_6;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_18, pio1_18;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_19, pio1_19;
+    #[cfg(not(feature = "33"))]
+    PIO1_7, pio1_7;
+    #[cfg(not(feature = "33"))]
+    PIO0_29, pio0_29;
+    #[cfg(not(feature = "33"))]
+    PIO0_30, pio0_30;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_20, pio1_20;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_21, pio1_21;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_11, pio1_11;
+    #[cfg(not(any(feature = "33", feature = "48")))]
+    PIO1_10, pio1_10;
+);
+
+standard_pins!(
+    PIO0_0, PIO0_1, PIO0_2, PIO0_3, PIO0_4, PIO0_5, PIO0_6, PIO0_7, PIO0_8,
+    PIO0_9, PIO0_12, PIO0_13, PIO0_14, PIO0_15,
+);
+
+#[cfg(not(feature = "20"))]
+standard_pins!(
+    PIO0_16, PIO0_17, PIO0_18, PIO0_19, PIO0_20, PIO0_21, PIO0_22, PIO0_23,
+    PIO0_24, PIO0_25, PIO0_26, PIO0_27, PIO0_28,
+);
+
+#[cfg(all(feature = "845", not(feature = "33")))]
+standard_pins!(
+    PIO0_29, PIO0_30, PIO0_31, PIO1_0, PIO1_1, PIO1_2, PIO1_3, PIO1_4, PIO1_5,
+    PIO1_6, PIO1_7, PIO1_8, PIO1_9,
+);
+
+#[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))]
+standard_pins!(
+    PIO1_10, PIO1_11, PIO1_12, PIO1_13, PIO1_14, PIO1_15, PIO1_16, PIO1_17,
+    PIO1_18, PIO1_19, PIO1_20, PIO1_21,
+);
@@ -116,8 +116,12 @@ pub mod delay;
 pub mod dma;
 pub mod gpio;
 pub mod i2c;
+pub mod inputmux;
+pub mod iocon;
 pub mod mrt;
+pub mod pint;
 pub mod pmu;
+pub mod sct;
 pub mod sleep;
 pub mod swm;
 pub mod syscon;
@@ -154,8 +158,12 @@ pub use self::ctimer::CTimer;
 pub use self::dma::DMA;
 pub use self::gpio::GPIO;
 pub use self::i2c::I2C;
+pub use self::inputmux::INPUTMUX;
+pub use self::iocon::IOCON;
 pub use self::mrt::MRT;
+pub use self::pint::PINT;
 pub use self::pmu::PMU;
+pub use self::sct::SCT;
 pub use self::swm::SWM;
 pub use self::syscon::SYSCON;
 pub use self::usart::USART;
@@ -219,12 +227,26 @@ pub struct Peripherals {
     /// I2C0-bus interface
     pub I2C0: I2C<pac::I2C0, init_state::Disabled>,
 
+    /// Input multiplexing
+    pub INPUTMUX: INPUTMUX,
+
+    /// I/O configuration (IOCON)
+    ///
+    /// By default, the IOCON peripheral is disabled.
+    pub IOCON: IOCON<init_state::Disabled>,
+
     /// Multi-Rate Timer (MRT)
     pub MRT0: MRT,
 
+    /// Pin interrupt and pattern match engine
+    pub PINT: PINT,
+
     /// Power Management Unit
     pub PMU: PMU,
 
+    /// State Configurable Timer (SCT)
+    pub SCT0: SCT<init_state::Disabled>,
+
     /// Switch matrix
     ///
     /// By default, the switch matrix is enabled on the LPC82x and disabled on
@@ -260,15 +282,19 @@ pub struct Peripherals {
     #[cfg(feature = "845")]
     /// USART3
     ///
-    /// USART3 and PIN_INT6 share an interrupt, this may cause difficulties
-    /// when trying to use both at the same time
+    /// USART3 and PIN_INT6 share an interrupt. Use
+    /// [`usart::SharedInterrupt`] to use both at the same time.
+    ///
+    /// [`usart::SharedInterrupt`]: usart/struct.SharedInterrupt.html
     pub USART3: USART<pac::USART3, init_state::Disabled>,
 
     #[cfg(feature = "845")]
     /// USART4
     ///
-    /// USART4 and PIN_INT7 share an interrupt, this may cause difficulties
-    /// when trying to use both at the same time
+    /// USART4 and PIN_INT7 share an interrupt. Use
+    /// [`usart::SharedInterrupt`] to use both at the same time.
+    ///
+    /// [`usart::SharedInterrupt`]: usart/struct.SharedInterrupt.html
     pub USART4: USART<pac::USART4, init_state::Disabled>,
 
     /// Self-wake-up timer (WKT)
@@ -347,34 +373,6 @@ pub struct Peripherals {
     /// allow you full, unprotected access to the peripheral.
     pub I2C3: pac::I2C3,
 
-    /// Input multiplexing
-    ///
-    /// A HAL API for this peripheral has not been implemented yet. In the
-    /// meantime, this field provides you with the raw register mappings, which
-    /// allow you full, unprotected access to the peripheral.
-    pub INPUTMUX: pac::INPUTMUX,
-
-    /// I/O configuration
-    ///
-    /// A HAL API for this peripheral has not been implemented yet. In the
-    /// meantime, this field provides you with the raw register mappings, which
-    /// allow you full, unprotected access to the peripheral.
-    pub IOCON: pac::IOCON,
-
-    /// Pin interrupt and pattern match engine
-    ///
-    /// A HAL API for this peripheral has not been implemented yet. In the
-    /// meantime, this field provides you with the raw register mappings, which
-    /// allow you full, unprotected access to the peripheral.
-    pub PINT: pac::PINT,
-
-    /// State Configurable Timer (SCT)
-    ///
-    /// A HAL API for this peripheral has not been implemented yet. In the
-    /// meantime, this field provides you with the raw register mappings, which
-    /// allow you full, unprotected access to the peripheral.
-    pub SCT0: pac::SCT0,
-
     /// SPI0
     ///
     /// A HAL API for this peripheral has not been implemented yet. In the
@@ -526,8 +524,12 @@ impl Peripherals {
             #[cfg(feature = "845")]
             GPIO: GPIO::new(p.GPIO),
             I2C0: I2C::new(p.I2C0),
+            INPUTMUX: INPUTMUX::new(p.INPUTMUX),
+            IOCON: IOCON::new(p.IOCON),
             MRT0: MRT::new(p.MRT0),
+            PINT: PINT::new(p.PINT),
             PMU: PMU::new(p.PMU),
+            SCT0: SCT::new(p.SCT0),
             #[cfg(feature = "82x")]
             SWM: unsafe { SWM::new_enabled(p.SWM0) },
             #[cfg(feature = "845")]
@@ -556,10 +558,6 @@ impl Peripherals {
             I2C1: p.I2C1,
             I2C2: p.I2C2,
             I2C3: p.I2C3,
-            INPUTMUX: p.INPUTMUX,
-            IOCON: p.IOCON,
-            PINT: p.PINT,
-            SCT0: p.SCT0,
             SPI0: p.SPI0,
             SPI1: p.SPI1,
             WWDT: p.WWDT,
@@ -0,0 +1,815 @@
+//! API for pin interrupts (PINT)
+//!
+//! The entry point to this API is [`PINT`]. It can be split into a
+//! [`Handle`], which controls the peripheral as a whole, and eight interrupt
+//! [`Slot`]s. Each slot can be connected to one GPIO input pin, and can then
+//! trigger an interrupt on edges or levels of that pin.
+//!
+//! The pin interrupt peripheral is described in the user manual, chapter 10.
+//!
+//! # Example
+//!
+//! ``` no_run
+//! use lpc8xx_hal::{
+//!     prelude::*,
+//!     pint::Sensitivity,
+//!     Peripherals,
+//! };
+//!
+//! let p = Peripherals::take().unwrap();
+//!
+//! let mut syscon = p.SYSCON.split();
+//! let swm = p.SWM.split();
+//! let pint = p.PINT.split();
+//!
+//! let button = swm.pins.pio0_4
+//!     .into_gpio_pin(&p.GPIO)
+//!     .into_input();
+//!
+//! let pint_handle = pint.handle.enable(&mut syscon.handle);
+//! let mut interrupt = pint.slots.slot0.enable(
+//!     button,
+//!     Sensitivity::FallingEdge,
+//!     &pint_handle,
+//! );
+//!
+//! interrupt.enable_interrupt();
+//! interrupt.enable_wakeup(&mut syscon.handle);
+//! ```
+//!
+//! # Pattern Matching
+//!
+//! Instead of triggering an interrupt for each pin on its own, the pin
+//! interrupt peripheral can evaluate a boolean expression over the inputs of
+//! all slots, and trigger interrupts or the `GPIO_INT_BMAT` output when it's
+//! true. Please refer to [`PatternMatch`] and [`Pattern`].
+//!
+//! # Shared interrupts
+//!
+//! On LPC845, pin interrupts 6 and 7 share their interrupt vectors with USART3
+//! and USART4. Please refer to [`usart::SharedInterrupt`], if you need to use
+//! both at the same time.
+//!
+//! [`PINT`]: struct.PINT.html
+//! [`Handle`]: struct.Handle.html
+//! [`Slot`]: struct.Slot.html
+//! [`PatternMatch`]: struct.PatternMatch.html
+//! [`Pattern`]: struct.Pattern.html
+//! [`usart::SharedInterrupt`]: ../usart/struct.SharedInterrupt.html
+
+use cortex_m::interrupt;
+
+use crate::{
+    gpio::direction,
+    init_state,
+    pac::{
+        self,
+        pint::{
+            CIENF, CIENR, FALL, ISEL, IST, PMCFG, PMCTRL, PMSRC, RISE, SIENF,
+            SIENR,
+        },
+        syscon::PINTSEL,
+        Interrupt, NVIC,
+    },
+    reg_proxy::RegProxy,
+    swm::{self, pin_state, Pin, PinTrait},
+    syscon,
+};
+
+/// Entry point to the PINT API
+///
+/// Use [`Peripherals`] to gain access to an instance of this struct.
+///
+/// Please refer to the [module documentation] for more information.
+///
+/// [`Peripherals`]: ../struct.Peripherals.html
+/// [module documentation]: index.html
+pub struct PINT {
+    pint: pac::PINT,
+}
+
+impl PINT {
+    pub(crate) fn new(pint: pac::PINT) -> Self {
+        PINT { pint }
+    }
+
+    /// Splits the PINT API into its component parts
+    ///
+    /// This is the regular way to access the PINT API. It exists as an
+    /// explicit step, as it's no longer possible to gain access to the raw
+    /// peripheral using [`PINT::free`] after you've called this method.
+    pub fn split(self) -> Parts {
+        Parts {
+            handle: Handle::new(self.pint),
+            slots: Slots::new(),
+            pattern_match: PatternMatch::new(),
+        }
+    }
+
+    /// Return the raw peripheral
+    ///
+    /// This method serves as an escape hatch from the HAL API. It returns the
+    /// raw peripheral, allowing you to do whatever you want with it, without
+    /// limitations imposed by the API.
+    ///
+    /// If you are using this method because a feature you need is missing from
+    /// the HAL API, please [open an issue] or, if an issue for your feature
+    /// request already exists, comment on the existing issue, so we can
+    /// prioritize it accordingly.
+    ///
+    /// [open an issue]: https://github.com/lpc-rs/lpc8xx-hal/issues
+    pub fn free(self) -> pac::PINT {
+        self.pint
+    }
+}
+
+/// The main API for the pin interrupt peripheral
+///
+/// Provides access to all types that make up the PINT API. Please refer to
+/// the [module documentation] for more information.
+///
+/// [module documentation]: index.html
+pub struct Parts {
+    /// Handle to the pin interrupt peripheral
+    pub handle: Handle<init_state::Disabled>,
+
+    /// The pin interrupt slots
+    pub slots: Slots,
+
+    /// The pattern match engine
+    pub pattern_match: PatternMatch,
+}
+
+/// Handle to the pin interrupt peripheral
+pub struct Handle<State = init_state::Enabled> {
+    _state: State,
+    pint: pac::PINT,
+}
+
+impl Handle<init_state::Disabled> {
+    pub(crate) fn new(pint: pac::PINT) -> Self {
+        Handle {
+            _state: init_state::Disabled,
+            pint,
+        }
+    }
+
+    /// Enable the pin interrupt peripheral
+    ///
+    /// On LPC82x, the pin interrupt peripheral is clocked together with the
+    /// GPIO peripheral, which needs to be enabled for any GPIO input pin to
+    /// exist. On LPC845, it has its own clock, which this method enables.
+    pub fn enable(
+        self,
+        syscon: &mut syscon::Handle,
+    ) -> Handle<init_state::Enabled> {
+        #[cfg(feature = "845")]
+        {
+            syscon.enable_clock(&self.pint);
+            syscon.clear_reset(&self.pint);
+        }
+        #[cfg(feature = "82x")]
+        let _ = syscon;
+
+        Handle {
+            _state: init_state::Enabled(()),
+            pint: self.pint,
+        }
+    }
+}
+
+impl Handle<init_state::Enabled> {
+    /// Disable the pin interrupt peripheral
+    ///
+    /// On LPC82x, this doesn't disable the clock, as it's shared with the GPIO
+    /// peripheral.
+    pub fn disable(
+        self,
+        syscon: &mut syscon::Handle,
+    ) -> Handle<init_state::Disabled> {
+        #[cfg(feature = "845")]
+        syscon.disable_clock(&self.pint);
+        #[cfg(feature = "82x")]
+        let _ = syscon;
+
+        Handle {
+            _state: init_state::Disabled,
+            pint: self.pint,
+        }
+    }
+}
+
+/// A pin interrupt slot
+///
+/// The pin interrupt peripheral has eight slots, each of which can be
+/// connected to one GPIO input pin using [`enable`]. `S` identifies the slot.
+///
+/// [`enable`]: #method.enable
+pub struct Slot<S, State = init_state::Disabled> {
+    ty: S,
+    _state: State,
+    regs: Registers,
+}
+
+impl<S> Slot<S, init_state::Disabled>
+where
+    S: SlotTrait,
+{
+    /// Connect a pin to this slot and enable interrupt detection
+    ///
+    /// Selects `pin` as the input of this slot (see user manual, section
+    /// 8.6.27), and configures the slot to detect the edges or levels
+    /// selected by `sensitivity`.
+    ///
+    /// This doesn't enable the interrupt in the NVIC. Use
+    /// [`PinInterrupt::enable_interrupt`] for that.
+    ///
+    /// [`PinInterrupt::enable_interrupt`]: struct.PinInterrupt.html#method.enable_interrupt
+    pub fn enable<'pint, 'gpio, T>(
+        self,
+        pin: Pin<T, pin_state::Gpio<'gpio, direction::Input>>,
+        sensitivity: Sensitivity,
+        _pint: &'pint Handle,
+    ) -> PinInterrupt<'pint, S, Pin<T, pin_state::Gpio<'gpio, direction::Input>>>
+    where
+        T: PinTrait,
+    {
+        // Pins on port 1 continue the numbering after port 0.
+        let intpin = T::PORT as u32 * 32 + T::ID as u32;
+
+        // Sound, as we only write this slot's register, and `intpin` refers
+        // to a valid pin.
+        self.regs.pintsel[S::INDEX].write(|w| unsafe { w.bits(intpin) });
+
+        let mut interrupt = PinInterrupt {
+            slot: Slot {
+                ty: self.ty,
+                _state: init_state::Enabled(()),
+                regs: self.regs,
+            },
+            pin,
+            _pint,
+        };
+        interrupt.set_sensitivity(sensitivity);
+
+        interrupt
+    }
+}
+
+/// A pin interrupt slot that is connected to a pin
+///
+/// Created by [`Slot::enable`]. `S` identifies the slot, `P` is the pin that
+/// is connected to it.
+///
+/// [`Slot::enable`]: struct.Slot.html#method.enable
+pub struct PinInterrupt<'pint, S, P> {
+    slot: Slot<S, init_state::Enabled>,
+    pin: P,
+    _pint: &'pint Handle,
+}
+
+impl<'pint, S, P> PinInterrupt<'pint, S, P>
+where
+    S: SlotTrait,
+{
+    /// Change the edges or levels that trigger the interrupt
+    ///
+    /// Clears any pending interrupt.
+    ///
+    /// See user manual, sections 10.7.1 to 10.7.7.
+    pub fn set_sensitivity(&mut self, sensitivity: Sensitivity) {
+        let regs = &self.slot.regs;
+
+        // Stop detecting while we reconfigure the slot.
+        // Sound, as we only write this slot's bit.
+        regs.cienr.write(|w| unsafe { w.bits(S::MASK) });
+        regs.cienf.write(|w| unsafe { w.bits(S::MASK) });
+
+        let level = match sensitivity {
+            Sensitivity::HighLevel | Sensitivity::LowLevel => true,
+            _ => false,
+        };
+
+        // ISEL is shared between all slots. We need a critical section to do
+        // the read-modify-write without interference.
+        interrupt::free(|_| {
+            // Sound, as we only change this slot's bit.
+            regs.isel.modify(|r, w| unsafe {
+                if level {
+                    w.bits(r.bits() | S::MASK)
+                } else {
+                    w.bits(r.bits() & !S::MASK)
+                }
+            });
+        });
+
+        // Clear any edges that have been detected so far. Writing IST in
+        // level-sensitive mode would switch the active level instead.
+        // Sound, as we only write this slot's bit.
+        regs.rise.write(|w| unsafe { w.bits(S::MASK) });
+        regs.fall.write(|w| unsafe { w.bits(S::MASK) });
+        if !level {
+            regs.ist.write(|w| unsafe { w.bits(S::MASK) });
+        }
+
+        // In edge-sensitive mode, IENR and IENF enable the rising and falling
+        // edge respectively. In level-sensitive mode, IENR enables the
+        // interrupt, and IENF selects the active level.
+        let (ienr, ienf) = match sensitivity {
+            Sensitivity::RisingEdge => (true, false),
+            Sensitivity::FallingEdge => (false, true),
+            Sensitivity::BothEdges => (true, true),
+            Sensitivity::HighLevel => (true, true),
+            Sensitivity::LowLevel => (true, false),
+        };
+
+        // Sound, as we only write this slot's bit.
+        if ienr {
+            regs.sienr.write(|w| unsafe { w.bits(S::MASK) });
+        }
+        if ienf {
+            regs.sienf.write(|w| unsafe { w.bits(S::MASK) });
+        }
+    }
+
+    /// Enable the interrupt for this slot
+    ///
+    /// This unmasks the slot's interrupt in the NVIC.
+    pub fn enable_interrupt(&mut self) {
+        // Safe, as there's no critical section that could be broken by this.
+        unsafe { NVIC::unmask(S::INTERRUPT) };
+    }
+
+    /// Disable the interrupt for this slot
+    ///
+    /// This masks the slot's interrupt in the NVIC. Detection of edges and
+    /// levels continues, and can be checked using [`is_pending`].
+    ///
+    /// [`is_pending`]: #method.is_pending
+    pub fn disable_interrupt(&mut self) {
+        NVIC::mask(S::INTERRUPT);
+    }
+
+    /// Indicates whether the interrupt for this slot is pending
+    ///
+    /// In edge-sensitive mode, this is the case once a selected edge has been
+    /// detected, until [`clear`] is called. In level-sensitive mode, this is
+    /// the case while the pin is at the active level.
+    ///
+    /// See user manual, section 10.7.10.
+    ///
+    /// [`clear`]: #method.clear
+    pub fn is_pending(&self) -> bool {
+        self.slot.regs.ist.read().bits() & S::MASK != 0
+    }
+
+    /// Clears the pending interrupt
+    ///
+    /// This only has an effect in edge-sensitive mode. In level-sensitive
+    /// mode, the interrupt remains pending until the pin leaves the active
+    /// level.
+    pub fn clear(&mut self) {
+        if self.slot.regs.isel.read().bits() & S::MASK != 0 {
+            return;
+        }
+
+        // Sound, as we only write this slot's bit.
+        self.slot.regs.ist.write(|w| unsafe { w.bits(S::MASK) });
+    }
+
+    /// Enable wake-up from deep-sleep and power-down modes
+    ///
+    /// Allows the interrupt of this slot to wake up the system from
+    /// deep-sleep and power-down modes. The interrupt also needs to be
+    /// enabled (see [`enable_interrupt`]).
+    ///
+    /// This method is not required when using the regular sleep mode.
+    ///
+    /// See user manual, section 8.6.29.
+    ///
+    /// [`enable_interrupt`]: #method.enable_interrupt
+    pub fn enable_wakeup(&mut self, syscon: &mut syscon::Handle) {
+        // Sound, as we only set this slot's bit.
+        syscon
+            .starterp0
+            .modify(|r, w| unsafe { w.bits(r.bits() | S::MASK) });
+    }
+
+    /// Disable wake-up from deep-sleep and power-down modes
+    pub fn disable_wakeup(&mut self, syscon: &mut syscon::Handle) {
+        // Sound, as we only clear this slot's bit.
+        syscon
+            .starterp0
+            .modify(|r, w| unsafe { w.bits(r.bits() & !S::MASK) });
+    }
+
+    /// Disconnect the pin from this slot
+    ///
+    /// Disables interrupt detection for this slot, and returns the slot and
+    /// the pin.
+    pub fn disable(self) -> (Slot<S, init_state::Disabled>, P) {
+        NVIC::mask(S::INTERRUPT);
+
+        // Sound, as we only write this slot's bit.
+        self.slot.regs.cienr.write(|w| unsafe { w.bits(S::MASK) });
+        self.slot.regs.cienf.write(|w| unsafe { w.bits(S::MASK) });
+
+        let slot = Slot {
+            ty: self.slot.ty,
+            _state: init_state::Disabled,
+            regs: self.slot.regs,
+        };
+
+        (slot, self.pin)
+    }
+}
+
+/// The edges or levels that trigger a pin interrupt
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sensitivity {
+    /// Trigger on rising edges
+    RisingEdge,
+
+    /// Trigger on falling edges
+    FallingEdge,
+
+    /// Trigger on rising and falling edges
+    BothEdges,
+
+    /// Trigger while the pin is HIGH
+    HighLevel,
+
+    /// Trigger while the pin is LOW
+    LowLevel,
+}
+
+/// The pattern match engine
+///
+/// Evaluates a [`Pattern`] over the inputs of the pin interrupt slots. While
+/// pattern matching is enabled, the slots no longer detect edges and levels on
+/// their own. Instead, each product term of the pattern triggers the
+/// interrupt of the slot that has the same index as the term's last slice.
+/// Use [`PinInterrupt::enable_interrupt`] to enable that interrupt.
+///
+/// See user manual, section 10.5.2.
+///
+/// [`Pattern`]: struct.Pattern.html
+/// [`PinInterrupt::enable_interrupt`]: struct.PinInterrupt.html#method.enable_interrupt
+pub struct PatternMatch {
+    pmctrl: RegProxy<PMCTRL>,
+    pmsrc: RegProxy<PMSRC>,
+    pmcfg: RegProxy<PMCFG>,
+}
+
+impl PatternMatch {
+    fn new() -> Self {
+        PatternMatch {
+            pmctrl: RegProxy::new(),
+            pmsrc: RegProxy::new(),
+            pmcfg: RegProxy::new(),
+        }
+    }
+
+    /// Load a pattern and enable pattern matching
+    ///
+    /// Replaces any pattern that was loaded before.
+    ///
+    /// See user manual, sections 10.7.11 to 10.7.13.
+    pub fn enable(&mut self, _pint: &Handle, pattern: &Pattern) {
+        // The inputs and conditions must be set up before pattern matching is
+        // enabled, or stale state could trigger spurious matches.
+        //
+        // Sound, as `Pattern` only creates valid configurations.
+        self.pmctrl.modify(|_, w| w.sel_pmatch().clear_bit());
+        self.pmsrc.write(|w| unsafe { w.bits(pattern.src) });
+        self.pmcfg.write(|w| unsafe { w.bits(pattern.cfg()) });
+        self.pmctrl.modify(|_, w| w.sel_pmatch().set_bit());
+    }
+
+    /// Disable pattern matching
+    ///
+    /// The slots go back to detecting edges and levels on their own.
+    pub fn disable(&mut self) {
+        self.pmctrl
+            .modify(|_, w| w.sel_pmatch().clear_bit().ena_rxev().clear_bit());
+    }
+
+    /// Drive the `GPIO_INT_BMAT` output when a product term matches
+    ///
+    /// The output is asserted while any product term of the pattern is true.
+    /// This also sends an event to the CPU (RXEV), which can be used to wake
+    /// it up from a `wfe` instruction.
+    pub fn enable_output<P>(
+        &mut self,
+        _function: &swm::Function<swm::GPIO_INT_BMAT, swm::state::Assigned<P>>,
+    ) {
+        self.pmctrl.modify(|_, w| w.ena_rxev().set_bit());
+    }
+
+    /// Stop driving the `GPIO_INT_BMAT` output
+    pub fn disable_output(&mut self) {
+        self.pmctrl.modify(|_, w| w.ena_rxev().clear_bit());
+    }
+
+    /// Indicates which product terms currently match
+    ///
+    /// Each bit corresponds to a slice. A bit is set, if the slice is the
+    /// last slice of a product term, and that product term is true.
+    pub fn matches(&self) -> u8 {
+        self.pmctrl.read().pmat().bits()
+    }
+}
+
+/// A boolean expression over the inputs of the pin interrupt slots
+///
+/// A pattern is a sum (OR) of product terms (AND). It is made up of up to
+/// eight slices, each of which evaluates one condition for one input. A
+/// product term consists of one or more consecutive slices, and is true if
+/// all its slices are true.
+///
+/// Slices are added in order, using [`slice`]. [`end_term`] ends the current
+/// product term, and the next slice starts a new one. The last product term
+/// is ended implicitly. Slices that are not used are configured to never
+/// match.
+///
+/// Load the pattern using [`PatternMatch::enable`].
+///
+/// # Example
+///
+/// ``` no_run
+/// # use lpc8xx_hal::pint::{Pattern, Condition, PinInterrupt, Slot0, Slot1};
+/// # fn example<A, B>(
+/// #     button_a: &PinInterrupt<Slot0, A>,
+/// #     button_b: &PinInterrupt<Slot1, B>,
+/// # ) {
+/// // Button A is held (low) while button B is pressed (falling edge)
+/// let pattern = Pattern::new()
+///     .slice(button_a, Condition::Low)
+///     .slice(button_b, Condition::FallingEdge);
+/// # }
+/// ```
+///
+/// See user manual, section 10.5.2.
+///
+/// [`slice`]: #method.slice
+/// [`end_term`]: #method.end_term
+/// [`PatternMatch::enable`]: struct.PatternMatch.html#method.enable
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    src: u32,
+    conditions: [Condition; 8],
+    endpoints: u8,
+    len: usize,
+}
+
+impl Pattern {
+    /// Create an empty pattern
+    pub fn new() -> Self {
+        Pattern {
+            src: 0,
+            conditions: [Condition::Never; 8],
+            endpoints: 0,
+            len: 0,
+        }
+    }
+
+    /// Add a slice to the current product term
+    ///
+    /// The slice evaluates `condition` for the pin that is connected to
+    /// `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the pattern already has eight slices.
+    pub fn slice<S, P>(
+        mut self,
+        _input: &PinInterrupt<S, P>,
+        condition: Condition,
+    ) -> Self
+    where
+        S: SlotTrait,
+    {
+        assert!(self.len < 8);
+
+        self.src |= (S::INDEX as u32) << (8 + 3 * self.len);
+        self.conditions[self.len] = condition;
+        self.len += 1;
+
+        self
+    }
+
+    /// End the current product term
+    ///
+    /// The last slice that was added becomes the endpoint of the product
+    /// term. When the product term is true, it triggers the interrupt of the
+    /// slot with the same index as that slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the current product term has no slices.
+    pub fn end_term(mut self) -> Self {
+        assert!(self.len > 0);
+        assert!(self.endpoints & (0x1 << (self.len - 1)) == 0);
+
+        self.endpoints |= 0x1 << (self.len - 1);
+
+        self
+    }
+
+    /// The value of PMCFG for this pattern
+    fn cfg(&self) -> u32 {
+        // Slice 7 is always an endpoint, so there's no bit for it in
+        // PROD_ENDPTS.
+        let mut endpoints = self.endpoints;
+        if self.len > 0 {
+            endpoints |= 0x1 << (self.len - 1);
+        }
+        let mut cfg = (endpoints & 0x7f) as u32;
+
+        for (i, condition) in self.conditions.iter().enumerate() {
+            cfg |= condition.bits() << (8 + 3 * i);
+        }
+
+        cfg
+    }
+}
+
+impl Default for Pattern {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The condition that a slice of a [`Pattern`] evaluates
+///
+/// The edge conditions are sticky: once the edge has been detected, the
+/// condition stays true until pattern matching is enabled again, or the
+/// product term that contains it matches.
+///
+/// See user manual, section 10.7.13.
+///
+/// [`Pattern`]: struct.Pattern.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Condition {
+    /// Always true
+    Always,
+
+    /// Never true
+    Never,
+
+    /// True while the input is HIGH
+    High,
+
+    /// True while the input is LOW
+    Low,
+
+    /// True once a rising edge has been detected (sticky)
+    RisingEdge,
+
+    /// True once a falling edge has been detected (sticky)
+    FallingEdge,
+
+    /// True once a rising or falling edge has been detected (sticky)
+    Edge,
+
+    /// True for one clock cycle after a rising or falling edge (not sticky)
+    Event,
+}
+
+impl Condition {
+    /// The value of the CFG field in PMCFG
+    fn bits(self) -> u32 {
+        match self {
+            Condition::Always => 0x0,
+            Condition::RisingEdge => 0x1,
+            Condition::FallingEdge => 0x2,
+            Condition::Edge => 0x3,
+            Condition::High => 0x4,
+            Condition::Low => 0x5,
+            Condition::Never => 0x6,
+            Condition::Event => 0x7,
+        }
+    }
+}
+
+/// The registers used by a slot
+///
+/// All of these registers are shared between the slots. Each slot restricts
+/// its access to the bit that is dedicated to it, and the one ISEL access that
+/// isn't restricted to a single bit is done in a critical section.
+struct Registers {
+    pintsel: RegProxy<PINTSEL>,
+    isel: RegProxy<ISEL>,
+    sienr: RegProxy<SIENR>,
+    cienr: RegProxy<CIENR>,
+    sienf: RegProxy<SIENF>,
+    cienf: RegProxy<CIENF>,
+    rise: RegProxy<RISE>,
+    fall: RegProxy<FALL>,
+    ist: RegProxy<IST>,
+}
+
+impl Registers {
+    fn new() -> Self {
+        Registers {
+            pintsel: RegProxy::new(),
+            isel: RegProxy::new(),
+            sienr: RegProxy::new(),
+            cienr: RegProxy::new(),
+            sienf: RegProxy::new(),
+            cienf: RegProxy::new(),
+            rise: RegProxy::new(),
+            fall: RegProxy::new(),
+            ist: RegProxy::new(),
+        }
+    }
+}
+
+/// Implemented for types that identify pin interrupt slots
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+pub trait SlotTrait {
+    /// The index of the slot
+    const INDEX: usize;
+
+    /// The bit that represents this slot in the PINT registers
+    const MASK: u32;
+
+    /// The interrupt that is triggered by this slot
+    const INTERRUPT: Interrupt;
+}
+
+macro_rules! slots {
+    ($($field:ident, $name:ident, $index:expr, $interrupt:ident;)*) => {
+        /// Provides access to all pin interrupt slots
+        #[allow(missing_docs)]
+        pub struct Slots {
+            $(pub $field: Slot<$name, init_state::Disabled>,)*
+        }
+
+        impl Slots {
+            fn new() -> Self {
+                Slots {
+                    $(
+                        $field: Slot {
+                            ty    : $name(()),
+                            _state: init_state::Disabled,
+                            regs  : Registers::new(),
+                        },
+                    )*
+                }
+            }
+        }
+
+
+        $(
+            /// Identifies a pin interrupt slot
+            pub struct $name(());
+
+            impl SlotTrait for $name {
+                const INDEX    : usize     = $index;
+                const MASK     : u32       = 0x1 << $index;
+                const INTERRUPT: Interrupt = Interrupt::$interrupt;
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "82x")]
+slots!(
+    slot0, Slot0, 0, PIN_INT0;
+    slot1, Slot1, 1, PIN_INT1;
+    slot2, Slot2, 2, PIN_INT2;
+    slot3, Slot3, 3, PIN_INT3;
+    slot4, Slot4, 4, PIN_INT4;
+    slot5, Slot5, 5, PIN_INT5;
+    slot6, Slot6, 6, PIN_INT6;
+    slot7, Slot7, 7, PIN_INT7;
+);
+
+#[cfg(feature = "845")]
+slots!(
+    slot0, Slot0, 0, PIN_INT0;
+    slot1, Slot1, 1, PIN_INT1;
+    slot2, Slot2, 2, PIN_INT2;
+    slot3, Slot3, 3, PIN_INT3;
+    slot4, Slot4, 4, PIN_INT4;
+    slot5, Slot5, 5, PIN_INT5;
+    slot6, Slot6, 6, PIN_INT6_USART3;
+    slot7, Slot7, 7, PIN_INT7_USART4;
+);
+
+reg!(PINTSEL, [PINTSEL; 8], pac::SYSCON, pintsel);
+reg!(ISEL, ISEL, pac::PINT, isel);
+reg!(SIENR, SIENR, pac::PINT, sienr);
+reg!(CIENR, CIENR, pac::PINT, cienr);
+reg!(SIENF, SIENF, pac::PINT, sienf);
+reg!(CIENF, CIENF, pac::PINT, cienf);
+reg!(RISE, RISE, pac::PINT, rise);
+reg!(FALL, FALL, pac::PINT, fall);
+reg!(IST, IST, pac::PINT, ist);
+reg!(PMCTRL, PMCTRL, pac::PINT, pmctrl);
+reg!(PMSRC, PMSRC, pac::PINT, pmsrc);
+reg!(PMCFG, PMCFG, pac::PINT, pmcfg);
@@ -0,0 +1,413 @@
+//! API for the State Configurable Timer (SCT)
+//!
+//! The entry point to this API is [`SCT`]. Currently, it can be used to
+//! generate PWM signals on multiple outputs (see [`SCT::start_pwm`]), or
+//! complementary, center-aligned PWM signals for motor control (see
+//! [`SCT::start_motor_pwm`]). It can also measure pulse widths and frequencies
+//! of input signals (see [`SCT::start_capture`]). For anything else, a custom
+//! state machine can be defined (see [`SCT::state_machine`]).
+//!
+//! The SCT peripheral is described in the user manual, chapter 16.
+//!
+//! # Example
+//!
+//! ``` no_run
+//! use lpc8xx_hal::{prelude::*, Peripherals};
+//!
+//! let p = Peripherals::take().unwrap();
+//!
+//! let mut swm = p.SWM.split();
+//! let mut syscon = p.SYSCON.split();
+//!
+//! let sct = p.SCT0.enable(&mut syscon.handle);
+//!
+//! // 1000 counts per period, clocked by the system clock
+//! let pwm = sct.start_pwm(1000, 0);
+//! let channels = pwm.split();
+//!
+//! let (sct_out0, _) = swm.movable_functions.sct_out0.assign(
+//!     swm.pins.pio0_12.into_swm_pin(),
+//!     &mut swm.handle,
+//! );
+//! let mut led = channels.channel0.attach(sct_out0);
+//!
+//! led.set_duty(250);
+//! ```
+//!
+//! [`SCT::start_pwm`]: struct.SCT.html#method.start_pwm
+//! [`SCT::start_motor_pwm`]: struct.SCT.html#method.start_motor_pwm
+//! [`SCT::start_capture`]: struct.SCT.html#method.start_capture
+//! [`SCT::state_machine`]: struct.SCT.html#method.state_machine
+
+pub mod capture;
+pub mod motor;
+pub mod pwm;
+pub mod state_machine;
+
+pub use self::{
+    capture::Capture, motor::MotorPwm, pwm::Pwm, state_machine::StateMachine,
+};
+
+use crate::{init_state, pac, swm, syscon};
+
+/// Entry point to the SCT API
+///
+/// Controls the clock and reset of the SCT peripheral. Use [`Peripherals`] to
+/// gain access to an instance of this struct.
+///
+/// Please refer to the [module documentation] for more information.
+///
+/// [`Peripherals`]: ../struct.Peripherals.html
+/// [module documentation]: index.html
+pub struct SCT<State = init_state::Enabled> {
+    sct: pac::SCT0,
+    _state: State,
+}
+
+impl SCT<init_state::Disabled> {
+    pub(crate) fn new(sct: pac::SCT0) -> Self {
+        SCT {
+            sct,
+            _state: init_state::Disabled,
+        }
+    }
+
+    /// Enable the SCT
+    ///
+    /// This method is only available, if `SCT` is in the [`Disabled`] state.
+    /// Code that attempts to call this method when the peripheral is already
+    /// enabled will not compile.
+    ///
+    /// Consumes this instance of `SCT` and returns another instance that has
+    /// its `State` type parameter set to [`Enabled`].
+    ///
+    /// [`Disabled`]: ../init_state/struct.Disabled.html
+    /// [`Enabled`]: ../init_state/struct.Enabled.html
+    pub fn enable(
+        self,
+        syscon: &mut syscon::Handle,
+    ) -> SCT<init_state::Enabled> {
+        syscon.enable_clock(&self.sct);
+        syscon.clear_reset(&self.sct);
+
+        SCT {
+            sct: self.sct,
+            _state: init_state::Enabled(()),
+        }
+    }
+}
+
+impl SCT<init_state::Enabled> {
+    /// Disable the SCT
+    ///
+    /// This method is only available, if `SCT` is in the [`Enabled`] state.
+    /// Code that attempts to call this method when the peripheral is already
+    /// disabled will not compile.
+    ///
+    /// Consumes this instance of `SCT` and returns another instance that has
+    /// its `State` type parameter set to [`Disabled`].
+    ///
+    /// [`Enabled`]: ../init_state/struct.Enabled.html
+    /// [`Disabled`]: ../init_state/struct.Disabled.html
+    pub fn disable(
+        self,
+        syscon: &mut syscon::Handle,
+    ) -> SCT<init_state::Disabled> {
+        syscon.disable_clock(&self.sct);
+
+        SCT {
+            sct: self.sct,
+            _state: init_state::Disabled,
+        }
+    }
+
+    /// Start generating PWM signals
+    ///
+    /// `period` is the length of a PWM period in counts of the SCT clock,
+    /// which is the system clock divided by `prescaler + 1`. It is also the
+    /// maximum duty cycle.
+    ///
+    /// On LPC82x, the SCT only has enough match registers for six channels,
+    /// if it runs as two 16-bit counters. `period` must therefore not be
+    /// larger than `0xffff` there.
+    ///
+    /// All channels start with a duty cycle of `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `period` is `0`, or if it's too large (see above).
+    pub fn start_pwm(self, period: u32, prescaler: u8) -> Pwm {
+        Pwm::new(self, period, prescaler)
+    }
+
+    /// Start generating center-aligned, complementary PWM signals
+    ///
+    /// The counter counts up to `top`, then back down to `0`, so a period is
+    /// `2 * top` counts of the SCT clock, which is the system clock divided by
+    /// `prescaler + 1`.
+    ///
+    /// `dead_time` is the number of counts between switching off one output
+    /// of a complementary pair, and switching on the other. It is inserted on
+    /// both edges of each pulse.
+    ///
+    /// `outputs` is a tuple of the SCT output functions of all phases, which
+    /// must be assigned to pins. See [`MotorPwm`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `top` is `0`, or if `dead_time` is not smaller than `top`.
+    ///
+    /// [`MotorPwm`]: motor/struct.MotorPwm.html
+    pub fn start_motor_pwm<O>(
+        self,
+        outputs: O,
+        top: u32,
+        prescaler: u8,
+        dead_time: u32,
+    ) -> MotorPwm<O>
+    where
+        O: motor::Outputs,
+    {
+        MotorPwm::new(self, outputs, top, prescaler, dead_time)
+    }
+
+    /// Start capturing the counter on input edges
+    ///
+    /// The counter runs freely, clocked by the system clock divided by
+    /// `prescaler + 1`. See [`Capture`] for more information.
+    ///
+    /// [`Capture`]: capture/struct.Capture.html
+    pub fn start_capture(self, prescaler: u8) -> Capture {
+        Capture::new(self, prescaler)
+    }
+
+    /// Start defining a custom state machine
+    ///
+    /// Returns a builder that is used to define the states and events of the
+    /// state machine. Nothing is written to the SCT until
+    /// [`Builder::start`] is called.
+    ///
+    /// [`Builder::start`]: state_machine/struct.Builder.html#method.start
+    pub fn state_machine(self) -> state_machine::Builder {
+        state_machine::Builder::new(self)
+    }
+
+    /// Halt the counter and put all registers into a known state
+    ///
+    /// All drivers call this before configuring the SCT, so nothing that a
+    /// previous user of the SCT configured is left behind. Only the match and
+    /// capture registers are left alone, as they have no effect without an
+    /// event or REGMODE bit that uses them.
+    ///
+    /// See user manual, section 16.6.
+    fn reset(&mut self) {
+        let sct = regs::sct();
+
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            sct.ctrl.write(|w| w.bits(regs::HALT_L | regs::HALT_H));
+
+            // Synchronize all inputs, as they would be after a reset.
+            sct.config.write(|w| w.bits(0xf << regs::INSYNC_OFFSET));
+
+            sct.limit.write(|w| w.bits(0));
+            sct.halt.write(|w| w.bits(0));
+            sct.stop.write(|w| w.bits(0));
+            sct.start.write(|w| w.bits(0));
+            sct.state.write(|w| w.bits(0));
+            sct.regmode.write(|w| w.bits(0));
+            sct.output.write(|w| w.bits(0));
+            sct.outputdirctrl.write(|w| w.bits(0));
+            sct.res.write(|w| w.bits(0));
+            sct.dmareq0.write(|w| w.bits(0));
+            sct.dmareq1.write(|w| w.bits(0));
+            sct.even.write(|w| w.bits(0));
+            sct.evflag.write(|w| w.bits(0xffff_ffff));
+            sct.conen.write(|w| w.bits(0));
+            sct.conflag.write(|w| w.bits(0xffff_ffff));
+
+            for ev in sct.ev.iter() {
+                ev.ev_state.write(|w| w.bits(0));
+                ev.ev_ctrl.write(|w| w.bits(0));
+            }
+            for out in sct.out.iter() {
+                out.out_set.write(|w| w.bits(0));
+                out.out_clr.write(|w| w.bits(0));
+            }
+        }
+    }
+}
+
+impl<State> SCT<State> {
+    /// Return the raw peripheral
+    ///
+    /// This method serves as an escape hatch from the HAL API. It returns the
+    /// raw peripheral, allowing you to do whatever you want with it, without
+    /// limitations imposed by the API.
+    ///
+    /// If you are using this method because a feature you need is missing from
+    /// the HAL API, please [open an issue] or, if an issue for your feature
+    /// request already exists, comment on the existing issue, so we can
+    /// prioritize it accordingly.
+    ///
+    /// [open an issue]: https://github.com/lpc-rs/lpc8xx-hal/issues
+    pub fn free(self) -> pac::SCT0 {
+        self.sct
+    }
+}
+
+/// Implemented for the SCT input functions
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+pub trait InputFunction {
+    /// The number of the SCT input
+    const INDEX: u32;
+}
+
+impl InputFunction for swm::SCT_PIN0 {
+    const INDEX: u32 = 0;
+}
+
+impl InputFunction for swm::SCT_PIN1 {
+    const INDEX: u32 = 1;
+}
+
+impl InputFunction for swm::SCT_PIN2 {
+    const INDEX: u32 = 2;
+}
+
+impl InputFunction for swm::SCT_PIN3 {
+    const INDEX: u32 = 3;
+}
+
+/// Access to the SCT registers
+///
+/// Most registers are accessed through the PAC's register block (see
+/// [`sct`]). The match and capture registers are accessed through their
+/// addresses instead. They share addresses, and in split mode, the halves of
+/// a register belong to different counters, which makes 16-bit accesses to
+/// them necessary.
+///
+/// [`sct`]: fn.sct.html
+///
+/// See user manual, section 16.6.
+mod regs {
+    use core::ptr;
+
+    use crate::pac;
+
+    pub const MATCH: usize = 0x100;
+    pub const MATCHREL: usize = 0x200;
+
+    // Share their addresses with the match and match reload registers
+    pub const CAP: usize = 0x100;
+    pub const CAPCTRL: usize = 0x200;
+
+    /// The number of outputs
+    #[cfg(feature = "82x")]
+    pub const NUM_OUTPUTS: usize = 6;
+    #[cfg(feature = "845")]
+    pub const NUM_OUTPUTS: usize = 7;
+
+    /// The number of match registers
+    #[cfg(feature = "82x")]
+    pub const NUM_MATCHES: usize = 5;
+    #[cfg(feature = "845")]
+    pub const NUM_MATCHES: usize = 8;
+
+    /// The number of events
+    pub const NUM_EVENTS: usize = 8;
+
+    // CONFIG
+    pub const UNIFY: u32 = 0x1 << 0;
+    pub const NORELOAD_L: u32 = 0x1 << 7;
+    pub const INSYNC_OFFSET: u32 = 9;
+    pub const AUTOLIMIT_L: u32 = 0x1 << 17;
+    pub const AUTOLIMIT_H: u32 = 0x1 << 18;
+
+    // CTRL
+    pub const DOWN_L: u32 = 0x1 << 0;
+    pub const HALT_L: u32 = 0x1 << 2;
+    pub const CLRCTR_L: u32 = 0x1 << 3;
+    pub const BIDIR_L: u32 = 0x1 << 4;
+    pub const PRE_L_OFFSET: u32 = 5;
+    pub const HALT_H: u32 = HALT_L << 16;
+    pub const CLRCTR_H: u32 = CLRCTR_L << 16;
+    pub const PRE_H_OFFSET: u32 = PRE_L_OFFSET + 16;
+
+    // EVn_CTRL
+    pub const HEVENT: u32 = 0x1 << 4;
+    pub const IOSEL_OFFSET: u32 = 6;
+    pub const IOCOND_OFFSET: u32 = 10;
+    pub const COMBMODE_MATCH: u32 = 0x1 << 12;
+    pub const COMBMODE_IO: u32 = 0x2 << 12;
+    pub const COMBMODE_AND: u32 = 0x3 << 12;
+    pub const STATELD: u32 = 0x1 << 14;
+    pub const STATEV_OFFSET: u32 = 15;
+    pub const DIRECTION_UP: u32 = 0x1 << 21;
+    pub const DIRECTION_DOWN: u32 = 0x2 << 21;
+
+    /// The SCT register block
+    ///
+    /// Also used by the parts of the drivers that don't own the SCT, like
+    /// PWM channels. Each of them only accesses the registers, or the bits of
+    /// registers, that belong to it.
+    pub fn sct() -> &'static pac::sct0::RegisterBlock {
+        // Sound, as all users of the register block only access what they
+        // own. Registers that are shared between users are only modified in
+        // critical sections.
+        unsafe { &*pac::SCT0::ptr() }
+    }
+
+    /// The address of the match or capture register at `offset`
+    fn address(offset: usize) -> usize {
+        pac::SCT0::ptr() as usize + offset
+    }
+
+    /// Read the match or capture register at `offset`
+    pub fn read(offset: usize) -> u32 {
+        // Sound, as all offsets used in this module refer to valid registers,
+        // and reading them has no side effects.
+        unsafe { ptr::read_volatile(address(offset) as *const u32) }
+    }
+
+    /// Write the match or capture register at `offset`
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that no one else accesses the register at
+    /// the same time, and that the written value is valid for the register.
+    pub unsafe fn write(offset: usize, value: u32) {
+        ptr::write_volatile(address(offset) as *mut u32, value)
+    }
+
+    /// Read the lower or upper half of the match register at `offset`
+    pub fn read_half(offset: usize, high: bool) -> u16 {
+        let offset = if high { offset + 2 } else { offset };
+
+        // Sound, as all offsets used in this module refer to valid registers,
+        // and reading them has no side effects.
+        unsafe { ptr::read_volatile(address(offset) as *const u16) }
+    }
+
+    /// Write the lower or upper half of the match register at `offset`
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that no one else accesses the same half of
+    /// the register at the same time.
+    pub unsafe fn write_half(offset: usize, high: bool, value: u16) {
+        let offset = if high { offset + 2 } else { offset };
+        ptr::write_volatile(address(offset) as *mut u16, value)
+    }
+
+    /// The offset of a register that exists once per event, output or match
+    ///
+    /// Event and output registers come in pairs, so their stride is 8. Match
+    /// registers have a stride of 4.
+    pub fn indexed(base: usize, stride: usize, index: usize) -> usize {
+        base + stride * index
+    }
+}
@@ -0,0 +1,447 @@
+//! Input capture using the SCT
+//!
+//! The counter runs freely as a single 32-bit counter. Each input has two
+//! events, which trigger on its rising and falling edges, and capture the
+//! counter. Events 0 to 3 are the rising edges of inputs 0 to 3, events 4 to
+//! 7 are the falling edges.
+//!
+//! On LPC845, each event has its own capture register with the same number.
+//! The SCT on LPC82x only has five capture registers, so both edges of an
+//! input share capture register 0 to 3, depending on the input number.
+//!
+//! See user manual, section 16.7.
+//!
+//! # Example
+//!
+//! ``` no_run
+//! use lpc8xx_hal::{prelude::*, Peripherals};
+//!
+//! use nb::block;
+//!
+//! let p = Peripherals::take().unwrap();
+//!
+//! let mut swm = p.SWM.split();
+//! let mut syscon = p.SYSCON.split();
+//!
+//! let sct = p.SCT0.enable(&mut syscon.handle);
+//! let capture = sct.start_capture(0);
+//!
+//! let (sct_pin0, _) = swm.movable_functions.sct_pin0.assign(
+//!     swm.pins.pio0_12.into_swm_pin(),
+//!     &mut swm.handle,
+//! );
+//! let mut input = capture.attach(sct_pin0);
+//!
+//! loop {
+//!     block!(input.wait()).unwrap();
+//!
+//!     if let Some(_pulse_width) = input.pulse_width() {
+//!         // do something with the pulse width
+//!     }
+//! }
+//! ```
+
+use core::marker::PhantomData;
+
+use cortex_m::interrupt;
+use void::Void;
+
+use crate::{
+    clock,
+    swm::{self, PinTrait},
+};
+
+use super::{regs, InputFunction, SCT};
+
+/// The number of SCT inputs
+const NUM_INPUTS: u32 = 4;
+
+/// The number of capture registers that are used
+///
+/// On LPC845, there is one for each edge of each input. On LPC82x, both edges
+/// of an input share one.
+#[cfg(feature = "82x")]
+const NUM_CAPTURES: u32 = 4;
+#[cfg(feature = "845")]
+const NUM_CAPTURES: u32 = 8;
+
+/// Input capture driver for the SCT
+///
+/// Use [`SCT::start_capture`] to get an instance of this struct, and
+/// [`Capture::attach`] to capture the edges of an input.
+///
+/// [`SCT::start_capture`]: ../struct.SCT.html#method.start_capture
+/// [`Capture::attach`]: #method.attach
+pub struct Capture {
+    sct: SCT,
+    prescaler: u8,
+}
+
+impl Capture {
+    pub(super) fn new(mut sct: SCT, prescaler: u8) -> Self {
+        sct.reset();
+        let registers = regs::sct();
+
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            // Synchronize all inputs, as they would be after a reset.
+            registers
+                .config
+                .write(|w| w.bits(regs::UNIFY | (0xf << regs::INSYNC_OFFSET)));
+            registers
+                .regmode
+                .write(|w| w.bits((0x1 << NUM_CAPTURES) - 1));
+
+            let mut capctrl = [0; NUM_CAPTURES as usize];
+            for input in 0..NUM_INPUTS {
+                let rise = rise_event(input);
+                let fall = fall_event(input);
+
+                capctrl[rise_capture(input)] |= 0x1 << rise;
+                capctrl[fall_capture(input)] |= 0x1 << fall;
+
+                // The events stay disabled, until the input is attached.
+                configure_event(rise, input, 1);
+                configure_event(fall, input, 2);
+            }
+            for (index, &value) in capctrl.iter().enumerate() {
+                regs::write(regs::indexed(regs::CAPCTRL, 4, index), value);
+            }
+
+            registers.ctrl.write(|w| {
+                w.bits(
+                    regs::CLRCTR_L | ((prescaler as u32) << regs::PRE_L_OFFSET),
+                )
+            });
+        }
+
+        Capture { sct, prescaler }
+    }
+
+    /// Start capturing the edges of an input
+    ///
+    /// Consumes the SCT input function, which must be assigned to a pin. The
+    /// returned [`CaptureInput`] borrows the `Capture`, so it can't be
+    /// stopped while any of its inputs are still in use.
+    ///
+    /// [`CaptureInput`]: struct.CaptureInput.html
+    pub fn attach<I, P>(
+        &self,
+        _: swm::Function<I, swm::state::Assigned<P>>,
+    ) -> CaptureInput<I>
+    where
+        I: InputFunction,
+        P: PinTrait,
+    {
+        // Sound, as the events belong to the input function we just consumed,
+        // and all values are valid.
+        for &event in [rise_event(I::INDEX), fall_event(I::INDEX)].iter() {
+            regs::sct().ev[event]
+                .ev_state
+                .write(|w| unsafe { w.bits(0x1) });
+        }
+
+        CaptureInput {
+            capture: self,
+            last_rise: None,
+            period: None,
+            pulse_width: None,
+            _input: PhantomData,
+        }
+    }
+
+    /// Stop capturing
+    ///
+    /// Halts the counter and returns the SCT. Any [`CaptureInput`]s must have
+    /// been dropped before.
+    ///
+    /// [`CaptureInput`]: struct.CaptureInput.html
+    pub fn stop(self) -> SCT {
+        // Sound, as we own the SCT.
+        regs::sct().ctrl.write(|w| unsafe { w.bits(regs::HALT_L) });
+        self.sct
+    }
+}
+
+/// An SCT input whose edges are being captured
+///
+/// Keeps track of the timestamps of the captured edges, to measure the
+/// period and pulse width of the input signal. All durations are in counts of
+/// the SCT clock.
+///
+/// Use [`Capture::attach`] to get an instance of this struct.
+///
+/// [`Capture::attach`]: struct.Capture.html#method.attach
+pub struct CaptureInput<'c, I> {
+    capture: &'c Capture,
+    last_rise: Option<u32>,
+    period: Option<u32>,
+    pulse_width: Option<u32>,
+    _input: PhantomData<I>,
+}
+
+impl<'c, I> CaptureInput<'c, I>
+where
+    I: InputFunction,
+{
+    /// Wait for an edge to be captured
+    ///
+    /// Returns the kind of edge that was captured, and updates the
+    /// measurements accordingly. Also acknowledges the interrupt, if it is
+    /// enabled.
+    ///
+    /// On LPC845, each edge has its own capture register. If both edges have
+    /// occurred since the last call, the earlier one is returned, and the
+    /// later one is left for the next call.
+    ///
+    /// On LPC82x, both edges share a capture register, which only holds the
+    /// timestamp of the later edge. If both edges have occurred since the last
+    /// call, it's unknown which one that was, so both are discarded, and the
+    /// measurement starts over with the next rising edge. `WouldBlock` is
+    /// returned in that case.
+    ///
+    /// On both targets, an edge is lost, if the same edge occurs again before
+    /// the first one has been handled.
+    pub fn wait(&mut self) -> nb::Result<Edge, Void> {
+        let rise_event = rise_event(I::INDEX);
+        let fall_event = fall_event(I::INDEX);
+        let rise_capture = rise_capture(I::INDEX);
+        let fall_capture = fall_capture(I::INDEX);
+
+        let flags = regs::sct().evflag.read().bits();
+        let rise = flags & (0x1 << rise_event) != 0;
+        let fall = flags & (0x1 << fall_event) != 0;
+
+        let edge = match (rise, fall) {
+            (false, false) => return Err(nb::Error::WouldBlock),
+            (true, false) => Edge::Rising,
+            (false, true) => Edge::Falling,
+            (true, true) if rise_capture == fall_capture => {
+                clear_flags((0x1 << rise_event) | (0x1 << fall_event));
+                self.last_rise = None;
+                return Err(nb::Error::WouldBlock);
+            }
+            (true, true) => {
+                let rise =
+                    regs::read(regs::indexed(regs::CAP, 4, rise_capture));
+                let fall =
+                    regs::read(regs::indexed(regs::CAP, 4, fall_capture));
+                earlier_edge(rise, fall)
+            }
+        };
+
+        let (event, capture) = match edge {
+            Edge::Rising => (rise_event, rise_capture),
+            Edge::Falling => (fall_event, fall_capture),
+        };
+
+        // Read the timestamp before clearing the flag. If the same edge occurs
+        // again after the flag has been cleared, it overwrites the capture
+        // register, and we'd read the timestamp of an edge that's still
+        // pending.
+        let timestamp = regs::read(regs::indexed(regs::CAP, 4, capture));
+        clear_flags(0x1 << event);
+
+        match edge {
+            Edge::Rising => {
+                if let Some(last_rise) = self.last_rise {
+                    self.period = Some(timestamp.wrapping_sub(last_rise));
+                }
+                self.last_rise = Some(timestamp);
+            }
+            Edge::Falling => {
+                if let Some(last_rise) = self.last_rise {
+                    self.pulse_width = Some(timestamp.wrapping_sub(last_rise));
+                }
+            }
+        }
+
+        Ok(edge)
+    }
+
+    /// Enable the SCT interrupt for both edges of this input
+    ///
+    /// Call [`wait`] in the interrupt handler, to acknowledge the interrupt.
+    ///
+    /// [`wait`]: #method.wait
+    pub fn enable_interrupts(&mut self) {
+        modify_even::<I>(true);
+    }
+
+    /// Disable the SCT interrupt for both edges of this input
+    pub fn disable_interrupts(&mut self) {
+        modify_even::<I>(false);
+    }
+
+    /// The time between the last two rising edges
+    ///
+    /// Returns `None`, if fewer than two rising edges have been captured.
+    pub fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    /// The time between the last rising edge and the following falling edge
+    ///
+    /// Returns `None`, if no falling edge has been captured after a rising
+    /// edge yet.
+    pub fn pulse_width(&self) -> Option<u32> {
+        self.pulse_width
+    }
+
+    /// The duty cycle of the input signal, scaled to `max`
+    ///
+    /// Returns `max`, if the input is HIGH for the whole period. Returns
+    /// `None`, if the period or pulse width are not known yet.
+    pub fn duty_cycle(&self, max: u32) -> Option<u32> {
+        Some(duty_cycle(self.period?, self.pulse_width?, max))
+    }
+
+    /// The frequency of the input signal in Hz
+    ///
+    /// `clock` must be the clock the SCT runs from, which is the system
+    /// clock. The prescaler is taken into account.
+    ///
+    /// Returns `None`, if the period is not known yet.
+    pub fn frequency<C>(&self, clock: &C) -> Option<u32>
+    where
+        C: clock::Frequency,
+    {
+        frequency(clock.hz(), self.capture.prescaler, self.period?)
+    }
+}
+
+/// An edge of an input signal
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Edge {
+    /// The input has changed from LOW to HIGH
+    Rising,
+
+    /// The input has changed from HIGH to LOW
+    Falling,
+}
+
+/// The event that triggers on the rising edge of an input
+fn rise_event(input: u32) -> usize {
+    input as usize
+}
+
+/// The event that triggers on the falling edge of an input
+fn fall_event(input: u32) -> usize {
+    (input + NUM_INPUTS) as usize
+}
+
+/// The capture register for the rising edge of an input
+fn rise_capture(input: u32) -> usize {
+    input as usize
+}
+
+/// The capture register for the falling edge of an input
+///
+/// This is the same as for the rising edge, if there aren't enough capture
+/// registers for both.
+fn fall_capture(input: u32) -> usize {
+    ((input + NUM_INPUTS) % NUM_CAPTURES) as usize
+}
+
+/// Which of two captured edges occurred first
+///
+/// Assumes that the edges are less than half the range of the counter apart.
+fn earlier_edge(rise: u32, fall: u32) -> Edge {
+    if (fall.wrapping_sub(rise) as i32) < 0 {
+        Edge::Falling
+    } else {
+        Edge::Rising
+    }
+}
+
+/// Compute the duty cycle from period and pulse width, scaled to `max`
+fn duty_cycle(period: u32, pulse_width: u32, max: u32) -> u32 {
+    let duty = pulse_width as u64 * max as u64 / (period as u64).max(1);
+    duty.min(max as u64) as u32
+}
+
+/// Compute the frequency from the SCT clock and the period
+///
+/// Returns `None`, if the period is `0`.
+fn frequency(clock_hz: u32, prescaler: u8, period: u32) -> Option<u32> {
+    let sct_clock = clock_hz / (prescaler as u32 + 1);
+    sct_clock.checked_div(period)
+}
+
+/// Acknowledge events by clearing their flags
+fn clear_flags(mask: u32) {
+    // Sound, as flags are cleared by writing `1`, and the caller only passes
+    // the flags of events that belong to it.
+    regs::sct().evflag.write(|w| unsafe { w.bits(mask) });
+}
+
+/// Enable or disable the interrupts of an input's events
+fn modify_even<I: InputFunction>(enable: bool) {
+    let mask = (0x1 << rise_event(I::INDEX)) | (0x1 << fall_event(I::INDEX));
+
+    // Sound, as the read-modify-write happens in a critical section, and only
+    // modifies the bits that belong to the input.
+    interrupt::free(|_| {
+        regs::sct().even.modify(|r, w| {
+            let even = r.bits();
+            let even = if enable { even | mask } else { even & !mask };
+            unsafe { w.bits(even) }
+        })
+    });
+}
+
+/// Configure an event to trigger on an input condition in state 0
+///
+/// The event is left disabled.
+///
+/// # Safety
+///
+/// The caller must own the event.
+unsafe fn configure_event(event: usize, input: u32, iocond: u32) {
+    regs::sct().ev[event].ev_ctrl.write(|w| {
+        w.bits(
+            (input << regs::IOSEL_OFFSET)
+                | (iocond << regs::IOCOND_OFFSET)
+                | regs::COMBMODE_IO,
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{duty_cycle, earlier_edge, frequency, Edge};
+
+    #[test]
+    fn duty_cycle_should_scale_pulse_width() {
+        assert_eq!(duty_cycle(1000, 250, 100), 25);
+        assert_eq!(duty_cycle(1000, 0, 100), 0);
+        assert_eq!(duty_cycle(3, 1, 0xffff_ffff), 0x5555_5555);
+    }
+
+    #[test]
+    fn duty_cycle_should_be_limited_to_max() {
+        assert_eq!(duty_cycle(1000, 1000, 100), 100);
+        assert_eq!(duty_cycle(1000, 1500, 100), 100);
+        assert_eq!(duty_cycle(0, 10, 100), 100);
+    }
+
+    #[test]
+    fn frequency_should_account_for_prescaler() {
+        assert_eq!(frequency(12_000_000, 0, 12_000), Some(1000));
+        assert_eq!(frequency(12_000_000, 3, 3_000), Some(1000));
+        assert_eq!(frequency(12_000_000, 255, 1), Some(46_875));
+    }
+
+    #[test]
+    fn frequency_should_handle_zero_period() {
+        assert_eq!(frequency(12_000_000, 0, 0), None);
+    }
+
+    #[test]
+    fn earlier_edge_should_handle_wrapping_counter() {
+        assert_eq!(earlier_edge(100, 200), Edge::Rising);
+        assert_eq!(earlier_edge(200, 100), Edge::Falling);
+        assert_eq!(earlier_edge(0xffff_fff0, 0x10), Edge::Rising);
+        assert_eq!(earlier_edge(0x10, 0xffff_fff0), Edge::Falling);
+    }
+}
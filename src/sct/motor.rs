@@ -0,0 +1,446 @@
+//! Center-aligned, complementary PWM generation using the SCT
+//!
+//! The counter runs in bidirectional mode. It counts up to the value of match
+//! register 0 (`top`), then back down to `0`. The output direction control
+//! reverses the meaning of set and clear while counting down, so every
+//! output event produces a pulse that is centered on `top`.
+//!
+//! Each phase drives a complementary pair of outputs: The high side is set
+//! by its own match while counting up, the low side is cleared by a match
+//! `dead_time` counts earlier. While counting down, the high side is cleared
+//! first, and the low side is set `dead_time` counts later. That way, both
+//! outputs are never active at the same time.
+//!
+//! An optional fault input forces all outputs LOW. As the output direction
+//! control also reverses the fault, it triggers one of two events, depending
+//! on the count direction: While counting up, the fault clears all outputs.
+//! While counting down, it sets them, which is reversed into a clear. If the
+//! fault coincides with an output event, the conflict resolution of each
+//! output is chosen to favor the fault in the direction where they conflict.
+//! The same events limit and halt the counter, so the outputs stay LOW until
+//! the fault is cleared.
+//!
+//! Each phase uses two events, and the fault input uses another two. That
+//! leaves 4 of the 8 events unused on LPC82x, and uses all 8 on LPC845.
+//!
+//! See user manual, section 16.7.
+
+use crate::swm::{
+    self, state::Assigned, Function, PinTrait, SCT_OUT0, SCT_OUT1, SCT_OUT2,
+    SCT_OUT3,
+};
+#[cfg(feature = "845")]
+use crate::swm::{SCT_OUT4, SCT_OUT5};
+
+use super::{regs, InputFunction, SCT};
+
+/// The number of phases
+#[cfg(feature = "82x")]
+const NUM_PHASES: usize = 2;
+#[cfg(feature = "845")]
+const NUM_PHASES: usize = 3;
+
+/// The event that is triggered by the fault input while counting up
+const FAULT_UP_EVENT: usize = 6;
+
+/// The event that is triggered by the fault input while counting down
+const FAULT_DOWN_EVENT: usize = 7;
+
+/// Center-aligned, complementary PWM driver for the SCT
+///
+/// Generates PWM signals for motor control, as complementary pairs of
+/// outputs with dead time inserted between them. Each phase uses two SCT
+/// outputs:
+///
+/// Phase | High side | Low side
+/// ----- | --------- | --------
+/// 0     | SCT_OUT0  | SCT_OUT1
+/// 1     | SCT_OUT2  | SCT_OUT3
+/// 2     | SCT_OUT4  | SCT_OUT5
+///
+/// Phase 2 is only available on LPC845, as the SCT on LPC82x doesn't have
+/// enough match registers. The SCT output functions of all phases must be
+/// assigned to pins using the switch matrix, and are passed to
+/// [`SCT::start_motor_pwm`] as a tuple (see [`Outputs`]).
+///
+/// All duty cycles are in counts of the SCT clock, and relative to `top`.
+/// Updated duty cycles take effect at the start of the next period, when the
+/// counter reaches `0`.
+///
+/// Use [`SCT::start_motor_pwm`] to get an instance of this struct.
+///
+/// [`SCT::start_motor_pwm`]: ../struct.SCT.html#method.start_motor_pwm
+/// [`Outputs`]: trait.Outputs.html
+pub struct MotorPwm<O> {
+    sct: SCT,
+    outputs: O,
+}
+
+impl<O> MotorPwm<O>
+where
+    O: Outputs,
+{
+    pub(super) fn new(
+        mut sct: SCT,
+        outputs: O,
+        top: u32,
+        prescaler: u8,
+        dead_time: u32,
+    ) -> Self {
+        assert!(top > 0 && top < 0xffff_ffff);
+        assert!(dead_time < top);
+
+        sct.reset();
+        let registers = regs::sct();
+
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            // Synchronize all inputs, as they would be after a reset.
+            registers.config.write(|w| {
+                w.bits(
+                    regs::UNIFY
+                        | regs::AUTOLIMIT_L
+                        | (0xf << regs::INSYNC_OFFSET),
+                )
+            });
+
+            regs::write(regs::MATCH, top);
+            regs::write(regs::MATCHREL, top);
+
+            let mut output = 0;
+            let mut dirctrl = 0;
+            for &phase in Phase::ALL.iter() {
+                for &(event, output_index, match_index) in
+                    [phase.high(), phase.low()].iter()
+                {
+                    let ev = &registers.ev[event];
+                    ev.ev_state.write(|w| w.bits(0x1));
+                    ev.ev_ctrl.write(|w| {
+                        w.bits(match_index as u32 | regs::COMBMODE_MATCH)
+                    });
+
+                    // Reverse set and clear while counting down
+                    dirctrl |= 0b01 << (output_index * 2);
+                }
+
+                let (high_event, high_output, _) = phase.high();
+                let (low_event, low_output, _) = phase.low();
+
+                registers.out[high_output]
+                    .out_set
+                    .write(|w| w.bits(0x1 << high_event));
+                registers.out[low_output]
+                    .out_clr
+                    .write(|w| w.bits(0x1 << low_event));
+
+                output |= 0x1 << low_output;
+
+                phase.write_duty(0, top, dead_time, regs::MATCH);
+                phase.write_duty(0, top, dead_time, regs::MATCHREL);
+            }
+            registers.outputdirctrl.write(|w| w.bits(dirctrl));
+            registers.output.write(|w| w.bits(output));
+
+            registers.ctrl.write(|w| {
+                w.bits(
+                    regs::CLRCTR_L
+                        | regs::BIDIR_L
+                        | ((prescaler as u32) << regs::PRE_L_OFFSET),
+                )
+            });
+        }
+
+        MotorPwm { sct, outputs }
+    }
+
+    /// Set the duty cycle of a phase
+    ///
+    /// The high side is active for `2 * duty` counts per period. The low side
+    /// is active for the rest of the period, minus the dead time on both
+    /// edges. `duty` is limited to [`max_duty`].
+    ///
+    /// [`max_duty`]: #method.max_duty
+    pub fn set_duty(&mut self, phase: Phase, duty: u32) {
+        let duty = duty.min(self.max_duty());
+
+        // Sound, as we own the SCT, and all values are valid. Reloading is
+        // suppressed while both match registers are updated, so the outputs
+        // never see one without the other.
+        unsafe {
+            let registers = regs::sct();
+            let config = registers.config.read().bits();
+            registers
+                .config
+                .write(|w| w.bits(config | regs::NORELOAD_L));
+            phase.write_duty(duty, top(), dead_time(), regs::MATCHREL);
+            registers.config.write(|w| w.bits(config));
+        }
+    }
+
+    /// Return the duty cycle of a phase
+    pub fn duty(&self, phase: Phase) -> u32 {
+        let (_, _, match_index) = phase.high();
+        top() + 1 - regs::read(regs::indexed(regs::MATCHREL, 4, match_index))
+    }
+
+    /// Return the maximum duty cycle
+    ///
+    /// This is `top`, minus the dead time.
+    pub fn max_duty(&self) -> u32 {
+        top() - dead_time()
+    }
+
+    /// Enable the fault input
+    ///
+    /// Once the fault input is at the given level, all outputs are forced LOW,
+    /// and the counter is halted. Use [`is_faulted`] to check for a fault, and
+    /// [`clear_fault`] to resume operation.
+    ///
+    /// The fault input is level-sensitive. If it is still active when the
+    /// fault is cleared, it will trigger again immediately.
+    ///
+    /// The fault input uses events 6 and 7, one for each count direction.
+    ///
+    /// [`is_faulted`]: #method.is_faulted
+    /// [`clear_fault`]: #method.clear_fault
+    pub fn enable_fault_input<I, P>(
+        &mut self,
+        _: swm::Function<I, swm::state::Assigned<P>>,
+        level: FaultLevel,
+    ) where
+        I: InputFunction,
+        P: PinTrait,
+    {
+        let iocond = match level {
+            FaultLevel::Low => 0,
+            FaultLevel::High => 3,
+        };
+
+        let ctrl = (I::INDEX << regs::IOSEL_OFFSET)
+            | (iocond << regs::IOCOND_OFFSET)
+            | regs::COMBMODE_IO;
+        let events = (0x1 << FAULT_UP_EVENT) | (0x1 << FAULT_DOWN_EVENT);
+
+        let registers = regs::sct();
+
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            for &(event, direction) in [
+                (FAULT_UP_EVENT, regs::DIRECTION_UP),
+                (FAULT_DOWN_EVENT, regs::DIRECTION_DOWN),
+            ]
+            .iter()
+            {
+                let ev = &registers.ev[event];
+                ev.ev_ctrl.write(|w| w.bits(ctrl | direction));
+                ev.ev_state.write(|w| w.bits(0x1));
+            }
+
+            let mut res = 0;
+            for &phase in Phase::ALL.iter() {
+                let (_, high_output, _) = phase.high();
+                let (_, low_output, _) = phase.low();
+
+                // While counting up, the fault clears all outputs. While
+                // counting down, it sets them, which the output direction
+                // control reverses into a clear.
+                for &output in [high_output, low_output].iter() {
+                    let out = &registers.out[output];
+                    out.out_clr.modify(|r, w| {
+                        w.bits(r.bits() | (0x1 << FAULT_UP_EVENT))
+                    });
+                    out.out_set.modify(|r, w| {
+                        w.bits(r.bits() | (0x1 << FAULT_DOWN_EVENT))
+                    });
+                }
+
+                // The high side is set by its own event, so it can only
+                // conflict with the fault while counting up. Resolve that as a
+                // clear. The low side is cleared by its own event, so it can
+                // only conflict with the fault while counting down. Resolve
+                // that as a set, which is reversed into a clear.
+                res |= 0b10 << (high_output * 2);
+                res |= 0b01 << (low_output * 2);
+            }
+            registers.res.write(|w| w.bits(res));
+
+            registers.limit.write(|w| w.bits(events));
+            registers.halt.write(|w| w.bits(events));
+        }
+    }
+
+    /// Indicates whether the fault input has halted the counter
+    pub fn is_faulted(&self) -> bool {
+        regs::sct().ctrl.read().bits() & regs::HALT_L != 0
+    }
+
+    /// Clear a fault and resume operation
+    ///
+    /// Restarts the counter from `0`, with all high sides inactive and all
+    /// low sides active. Does nothing, if no fault occurred.
+    pub fn clear_fault(&mut self) {
+        if !self.is_faulted() {
+            return;
+        }
+
+        let mut output = 0;
+        for &phase in Phase::ALL.iter() {
+            let (_, low_output, _) = phase.low();
+            output |= 0x1 << low_output;
+        }
+
+        // Sound, as we own the SCT, and all values are valid. The output
+        // register can only be written while the counter is halted. The fault
+        // might have occurred while counting down, so the direction needs to
+        // be reset along with the counter.
+        unsafe {
+            let registers = regs::sct();
+            let ctrl = registers.ctrl.read().bits() & !regs::DOWN_L;
+            registers.ctrl.write(|w| w.bits(ctrl | regs::CLRCTR_L));
+            registers.output.write(|w| w.bits(output));
+            registers.ctrl.write(|w| w.bits(ctrl & !regs::HALT_L));
+        }
+    }
+
+    /// Stop generating PWM signals
+    ///
+    /// Halts the counter and returns the SCT, as well as the output functions.
+    /// The outputs keep their current level.
+    pub fn stop(self) -> (SCT, O) {
+        // Sound, as we own the SCT.
+        regs::sct().ctrl.write(|w| unsafe { w.bits(regs::HALT_L) });
+        (self.sct, self.outputs)
+    }
+}
+
+/// Implemented for the SCT output functions used by [`MotorPwm`]
+///
+/// Implemented for a tuple of the assigned SCT_OUTn functions of all phases,
+/// in the order of the outputs: `(SCT_OUT0, SCT_OUT1, SCT_OUT2, SCT_OUT3)`
+/// on LPC82x, and additionally `SCT_OUT4` and `SCT_OUT5` on LPC845.
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+///
+/// [`MotorPwm`]: struct.MotorPwm.html
+pub trait Outputs {}
+
+#[cfg(feature = "82x")]
+impl<P0, P1, P2, P3> Outputs
+    for (
+        Function<SCT_OUT0, Assigned<P0>>,
+        Function<SCT_OUT1, Assigned<P1>>,
+        Function<SCT_OUT2, Assigned<P2>>,
+        Function<SCT_OUT3, Assigned<P3>>,
+    )
+where
+    P0: PinTrait,
+    P1: PinTrait,
+    P2: PinTrait,
+    P3: PinTrait,
+{
+}
+
+#[cfg(feature = "845")]
+impl<P0, P1, P2, P3, P4, P5> Outputs
+    for (
+        Function<SCT_OUT0, Assigned<P0>>,
+        Function<SCT_OUT1, Assigned<P1>>,
+        Function<SCT_OUT2, Assigned<P2>>,
+        Function<SCT_OUT3, Assigned<P3>>,
+        Function<SCT_OUT4, Assigned<P4>>,
+        Function<SCT_OUT5, Assigned<P5>>,
+    )
+where
+    P0: PinTrait,
+    P1: PinTrait,
+    P2: PinTrait,
+    P3: PinTrait,
+    P4: PinTrait,
+    P5: PinTrait,
+{
+}
+
+/// Identifies a phase of a [`MotorPwm`]
+///
+/// [`MotorPwm`]: struct.MotorPwm.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Phase 0, controls SCT_OUT0 (high side) and SCT_OUT1 (low side)
+    Phase0 = 0,
+
+    /// Phase 1, controls SCT_OUT2 (high side) and SCT_OUT3 (low side)
+    Phase1 = 1,
+
+    /// Phase 2, controls SCT_OUT4 (high side) and SCT_OUT5 (low side)
+    #[cfg(feature = "845")]
+    Phase2 = 2,
+}
+
+impl Phase {
+    #[cfg(feature = "82x")]
+    const ALL: [Phase; NUM_PHASES] = [Phase::Phase0, Phase::Phase1];
+    #[cfg(feature = "845")]
+    const ALL: [Phase; NUM_PHASES] =
+        [Phase::Phase0, Phase::Phase1, Phase::Phase2];
+
+    /// Event, output and match register of the high side
+    fn high(self) -> (usize, usize, usize) {
+        let n = self as usize;
+        (2 * n, 2 * n, 2 * n + 1)
+    }
+
+    /// Event, output and match register of the low side
+    fn low(self) -> (usize, usize, usize) {
+        let n = self as usize;
+        (2 * n + 1, 2 * n + 1, 2 * n + 2)
+    }
+
+    /// Write the match or match reload registers for a duty cycle
+    ///
+    /// The high side switches at `top + 1 - duty`, which never matches for a
+    /// duty cycle of `0`. The low side switches `dead_time` counts earlier.
+    ///
+    /// # Safety
+    ///
+    /// See [`regs::write`].
+    unsafe fn write_duty(
+        self,
+        duty: u32,
+        top: u32,
+        dead_time: u32,
+        base: usize,
+    ) {
+        let (_, _, high_match) = self.high();
+        let (_, _, low_match) = self.low();
+        let value = top + 1 - duty;
+
+        regs::write(regs::indexed(base, 4, high_match), value);
+        regs::write(regs::indexed(base, 4, low_match), value - dead_time);
+    }
+}
+
+/// The level of the fault input that indicates a fault
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FaultLevel {
+    /// A fault is indicated by a LOW level
+    Low,
+
+    /// A fault is indicated by a HIGH level
+    High,
+}
+
+/// The current value of `top`
+fn top() -> u32 {
+    regs::read(regs::MATCHREL)
+}
+
+/// The dead time, derived from the match reload registers of phase 0
+fn dead_time() -> u32 {
+    let (_, _, high_match) = Phase::Phase0.high();
+    let (_, _, low_match) = Phase::Phase0.low();
+
+    regs::read(regs::indexed(regs::MATCHREL, 4, high_match))
+        - regs::read(regs::indexed(regs::MATCHREL, 4, low_match))
+}
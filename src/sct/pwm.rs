@@ -0,0 +1,519 @@
+//! PWM generation using the SCT
+//!
+//! Match register 0 defines the period. It limits the counter, and the event
+//! that it triggers sets all outputs. Each channel has its own match register
+//! and event, which clears the channel's output. Duty cycles are written to
+//! the match reload registers, so they only take effect at the end of a
+//! period, which prevents glitches.
+//!
+//! On LPC82x, the SCT only has five match registers. To support six channels,
+//! it is used as two 16-bit counters (L and H) with the same period. Channels
+//! 0 to 3 use the L counter, channels 4 and 5 use the H counter.
+//!
+//! See user manual, section 16.7.
+
+use core::marker::PhantomData;
+
+use embedded_hal::PwmPin;
+
+use crate::swm::{self, PinTrait};
+
+#[cfg(feature = "845")]
+use crate::swm::SCT_OUT6;
+use crate::swm::{SCT_OUT0, SCT_OUT1, SCT_OUT2, SCT_OUT3, SCT_OUT4, SCT_OUT5};
+
+use super::{regs, SCT};
+
+/// Indicates whether the SCT is split into two 16-bit counters
+const SPLIT: bool = cfg!(feature = "82x");
+
+/// A match value that never matches, as it's above the limit
+#[cfg(feature = "82x")]
+const NEVER: u32 = 0xffff;
+#[cfg(feature = "845")]
+const NEVER: u32 = 0xffff_ffff;
+
+/// The events that limit the counters and set the outputs
+///
+/// Contains the event number and whether it belongs to the H counter.
+#[cfg(feature = "82x")]
+const PERIOD_EVENTS: [(usize, bool); 2] = [(0, false), (5, true)];
+#[cfg(feature = "845")]
+const PERIOD_EVENTS: [(usize, bool); 1] = [(0, false)];
+
+/// PWM driver for the SCT
+///
+/// Controls the period of all channels, and implements [`embedded_hal::Pwm`]
+/// to control the channels. Use [`Pwm::split`] to control the channels
+/// individually instead.
+///
+/// The `Time` and `Duty` types are in counts of the SCT clock.
+///
+/// Use [`SCT::start_pwm`] to get an instance of this struct.
+///
+/// [`embedded_hal::Pwm`]: https://docs.rs/embedded-hal/0.2.*/embedded_hal/trait.Pwm.html
+/// [`Pwm::split`]: #method.split
+/// [`SCT::start_pwm`]: ../struct.SCT.html#method.start_pwm
+pub struct Pwm {
+    sct: SCT,
+}
+
+impl Pwm {
+    pub(super) fn new(mut sct: SCT, period: u32, prescaler: u8) -> Self {
+        assert!(period > 0 && period - 1 < NEVER);
+
+        sct.reset();
+        let registers = regs::sct();
+
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            let config = if SPLIT {
+                regs::AUTOLIMIT_L | regs::AUTOLIMIT_H
+            } else {
+                regs::UNIFY | regs::AUTOLIMIT_L
+            };
+
+            // Synchronize all inputs, as they would be after a reset.
+            registers
+                .config
+                .write(|w| w.bits(config | (0xf << regs::INSYNC_OFFSET)));
+
+            for &(event, high) in PERIOD_EVENTS.iter() {
+                set_match(regs::MATCH, 0, high, period - 1);
+                set_match(regs::MATCHREL, 0, high, period - 1);
+                configure_event(event, 0, high);
+            }
+
+            let mut res = 0;
+            for &channel in Channel::ALL.iter() {
+                let value = duty_to_match(0, period);
+                set_match(
+                    regs::MATCH,
+                    channel.match_index(),
+                    channel.high(),
+                    value,
+                );
+                set_match(
+                    regs::MATCHREL,
+                    channel.match_index(),
+                    channel.high(),
+                    value,
+                );
+                configure_event(
+                    channel.event(),
+                    channel.match_index(),
+                    channel.high(),
+                );
+                channel.enable();
+
+                // Clear the output, if setting and clearing conflict
+                res |= 0b10 << (channel.output() * 2);
+            }
+            registers.res.write(|w| w.bits(res));
+
+            let mut ctrl =
+                regs::CLRCTR_L | ((prescaler as u32) << regs::PRE_L_OFFSET);
+            if SPLIT {
+                ctrl |=
+                    regs::CLRCTR_H | ((prescaler as u32) << regs::PRE_H_OFFSET);
+            }
+            registers.ctrl.write(|w| w.bits(ctrl));
+        }
+
+        Pwm { sct }
+    }
+
+    /// Split the driver into its channels
+    ///
+    /// This allows the channels to be controlled individually, using
+    /// [`embedded_hal::PwmPin`]. The period can no longer be changed
+    /// afterwards.
+    ///
+    /// [`embedded_hal::PwmPin`]: https://docs.rs/embedded-hal/0.2.*/embedded_hal/trait.PwmPin.html
+    pub fn split(self) -> Channels {
+        Channels {
+            channel0: DetachedPwmPin::new(Channel::Channel0),
+            channel1: DetachedPwmPin::new(Channel::Channel1),
+            channel2: DetachedPwmPin::new(Channel::Channel2),
+            channel3: DetachedPwmPin::new(Channel::Channel3),
+            channel4: DetachedPwmPin::new(Channel::Channel4),
+            channel5: DetachedPwmPin::new(Channel::Channel5),
+            #[cfg(feature = "845")]
+            channel6: DetachedPwmPin::new(Channel::Channel6),
+        }
+    }
+
+    /// Stop generating PWM signals
+    ///
+    /// Halts the counter and returns the SCT. The outputs keep their current
+    /// level.
+    pub fn stop(self) -> SCT {
+        // Sound, as we own the SCT.
+        regs::sct()
+            .ctrl
+            .write(|w| unsafe { w.bits(regs::HALT_L | regs::HALT_H) });
+        self.sct
+    }
+}
+
+impl embedded_hal::Pwm for Pwm {
+    type Channel = Channel;
+    type Time = u32;
+    type Duty = u32;
+
+    /// Disable a channel
+    ///
+    /// The output is cleared at the end of the current period, and stays LOW
+    /// until the channel is enabled again.
+    fn disable(&mut self, channel: Self::Channel) {
+        channel.disable();
+    }
+
+    /// Enable a channel
+    ///
+    /// All channels are enabled by default.
+    fn enable(&mut self, channel: Self::Channel) {
+        channel.enable();
+    }
+
+    fn get_period(&self) -> Self::Time {
+        period(false)
+    }
+
+    fn get_duty(&self, channel: Self::Channel) -> Self::Duty {
+        channel.duty()
+    }
+
+    fn get_max_duty(&self) -> Self::Duty {
+        period(false)
+    }
+
+    fn set_duty(&mut self, channel: Self::Channel, duty: Self::Duty) {
+        channel.set_duty(duty);
+    }
+
+    /// Set the period
+    ///
+    /// Takes effect at the end of the current period. The duty cycles of the
+    /// channels are not scaled, so they should be updated after the period
+    /// has been changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `period` is `0`, or if it's too large (see
+    /// [`SCT::start_pwm`]).
+    ///
+    /// [`SCT::start_pwm`]: ../struct.SCT.html#method.start_pwm
+    fn set_period<P>(&mut self, period: P)
+    where
+        P: Into<Self::Time>,
+    {
+        let period = period.into();
+        assert!(period > 0 && period - 1 < NEVER);
+
+        for &(_, high) in PERIOD_EVENTS.iter() {
+            let old = get_match(regs::MATCHREL, 0, high);
+
+            // Sound, as we own the SCT, and all values are valid.
+            unsafe {
+                set_match(regs::MATCHREL, 0, high, period - 1);
+
+                // Channels with a duty cycle of `0` match at the end of the
+                // period, so they need to follow it.
+                for &channel in Channel::ALL.iter() {
+                    let index = channel.match_index();
+                    if channel.high() == high
+                        && get_match(regs::MATCHREL, index, high) == old
+                    {
+                        set_match(regs::MATCHREL, index, high, period - 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Identifies a PWM channel
+///
+/// Each channel controls the SCT output with the same number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Channel {
+    /// Channel 0, controls SCT_OUT0
+    Channel0 = 0,
+
+    /// Channel 1, controls SCT_OUT1
+    Channel1 = 1,
+
+    /// Channel 2, controls SCT_OUT2
+    Channel2 = 2,
+
+    /// Channel 3, controls SCT_OUT3
+    Channel3 = 3,
+
+    /// Channel 4, controls SCT_OUT4
+    Channel4 = 4,
+
+    /// Channel 5, controls SCT_OUT5
+    Channel5 = 5,
+
+    /// Channel 6, controls SCT_OUT6
+    #[cfg(feature = "845")]
+    Channel6 = 6,
+}
+
+impl Channel {
+    #[cfg(feature = "82x")]
+    const ALL: [Channel; regs::NUM_OUTPUTS] = [
+        Channel::Channel0,
+        Channel::Channel1,
+        Channel::Channel2,
+        Channel::Channel3,
+        Channel::Channel4,
+        Channel::Channel5,
+    ];
+    #[cfg(feature = "845")]
+    const ALL: [Channel; regs::NUM_OUTPUTS] = [
+        Channel::Channel0,
+        Channel::Channel1,
+        Channel::Channel2,
+        Channel::Channel3,
+        Channel::Channel4,
+        Channel::Channel5,
+        Channel::Channel6,
+    ];
+
+    /// The SCT output controlled by this channel
+    fn output(self) -> usize {
+        self as usize
+    }
+
+    /// Indicates whether the channel belongs to the H counter
+    fn high(self) -> bool {
+        SPLIT && self.output() >= 4
+    }
+
+    /// The match register used by this channel
+    fn match_index(self) -> usize {
+        if self.high() {
+            self.output() - 3
+        } else {
+            self.output() + 1
+        }
+    }
+
+    /// The event that clears the output of this channel
+    fn event(self) -> usize {
+        if self.high() {
+            self.output() + 2
+        } else {
+            self.output() + 1
+        }
+    }
+
+    /// The event that sets the output of this channel
+    fn period_event(self) -> usize {
+        if self.high() {
+            5
+        } else {
+            0
+        }
+    }
+
+    fn enable(self) {
+        let out = &regs::sct().out[self.output()];
+
+        // Sound, as the output registers are only written by the owner of the
+        // channel, and all values are valid.
+        unsafe {
+            out.out_set.write(|w| w.bits(0x1 << self.period_event()));
+            out.out_clr.write(|w| w.bits(0x1 << self.event()));
+        }
+    }
+
+    fn disable(self) {
+        let out = &regs::sct().out[self.output()];
+
+        // Sound, as the output registers are only written by the owner of the
+        // channel, and all values are valid.
+        unsafe {
+            out.out_set.write(|w| w.bits(0));
+            out.out_clr.write(|w| {
+                w.bits((0x1 << self.event()) | (0x1 << self.period_event()))
+            });
+        }
+    }
+
+    fn duty(self) -> u32 {
+        let value = get_match(regs::MATCHREL, self.match_index(), self.high());
+        match_to_duty(value, period(self.high()))
+    }
+
+    fn set_duty(self, duty: u32) {
+        let value = duty_to_match(duty, period(self.high()));
+
+        // Sound, as this half of the match reload register is only written by
+        // the owner of the channel, and all values are valid.
+        unsafe {
+            set_match(regs::MATCHREL, self.match_index(), self.high(), value)
+        }
+    }
+}
+
+/// The PWM channels of the SCT
+///
+/// Returned by [`Pwm::split`].
+///
+/// [`Pwm::split`]: struct.Pwm.html#method.split
+#[allow(missing_docs)]
+pub struct Channels {
+    pub channel0: DetachedPwmPin<SCT_OUT0>,
+    pub channel1: DetachedPwmPin<SCT_OUT1>,
+    pub channel2: DetachedPwmPin<SCT_OUT2>,
+    pub channel3: DetachedPwmPin<SCT_OUT3>,
+    pub channel4: DetachedPwmPin<SCT_OUT4>,
+    pub channel5: DetachedPwmPin<SCT_OUT5>,
+    #[cfg(feature = "845")]
+    pub channel6: DetachedPwmPin<SCT_OUT6>,
+}
+
+/// A PWM channel that is not assigned to a pin yet
+///
+/// Use [`attach`] to assign an output to it.
+///
+/// [`attach`]: #method.attach
+pub struct DetachedPwmPin<SctOutput> {
+    channel: Channel,
+    _output: PhantomData<SctOutput>,
+}
+
+impl<SctOutput> DetachedPwmPin<SctOutput> {
+    fn new(channel: Channel) -> Self {
+        DetachedPwmPin {
+            channel,
+            _output: PhantomData,
+        }
+    }
+
+    /// Assigns a pin to a `DetachedPwmPin`,
+    /// allowing it to be used as a pwm output
+    pub fn attach<P>(
+        self,
+        _: swm::Function<SctOutput, swm::state::Assigned<P>>,
+    ) -> SctPwmPin
+    where
+        P: PinTrait,
+    {
+        SctPwmPin {
+            channel: self.channel,
+        }
+    }
+}
+
+/// A PWM channel that is assigned to an output pin
+pub struct SctPwmPin {
+    channel: Channel,
+}
+
+impl PwmPin for SctPwmPin {
+    type Duty = u32;
+
+    /// Disable the channel
+    ///
+    /// The output is cleared at the end of the current period, and stays LOW
+    /// until the channel is enabled again.
+    fn disable(&mut self) {
+        self.channel.disable();
+    }
+
+    /// Enable the channel
+    ///
+    /// All channels are enabled by default.
+    fn enable(&mut self) {
+        self.channel.enable();
+    }
+
+    fn get_duty(&self) -> Self::Duty {
+        self.channel.duty()
+    }
+
+    fn get_max_duty(&self) -> Self::Duty {
+        period(self.channel.high())
+    }
+
+    fn set_duty(&mut self, duty: Self::Duty) {
+        self.channel.set_duty(duty);
+    }
+}
+
+/// The current period of the L or H counter
+fn period(high: bool) -> u32 {
+    get_match(regs::MATCHREL, 0, high) + 1
+}
+
+/// Convert a duty cycle into the value of a channel's match register
+///
+/// A duty cycle of `0` matches at the same time as the period match, and the
+/// conflict resolution clears the output. A duty cycle equal to or larger
+/// than the period never matches, so the output is never cleared.
+fn duty_to_match(duty: u32, period: u32) -> u32 {
+    if duty == 0 {
+        period - 1
+    } else if duty >= period {
+        NEVER
+    } else {
+        duty - 1
+    }
+}
+
+/// Convert the value of a channel's match register into a duty cycle
+fn match_to_duty(value: u32, period: u32) -> u32 {
+    if value == period - 1 {
+        0
+    } else if value >= period {
+        period
+    } else {
+        value + 1
+    }
+}
+
+/// Read a match or match reload register of the L, H or unified counter
+fn get_match(base: usize, index: usize, high: bool) -> u32 {
+    let offset = regs::indexed(base, 4, index);
+
+    if SPLIT {
+        regs::read_half(offset, high) as u32
+    } else {
+        regs::read(offset)
+    }
+}
+
+/// Write a match or match reload register of the L, H or unified counter
+///
+/// # Safety
+///
+/// See [`regs::write`].
+unsafe fn set_match(base: usize, index: usize, high: bool, value: u32) {
+    let offset = regs::indexed(base, 4, index);
+
+    if SPLIT {
+        regs::write_half(offset, high, value as u16)
+    } else {
+        regs::write(offset, value)
+    }
+}
+
+/// Configure an event to trigger on a match in state 0
+///
+/// # Safety
+///
+/// The caller must own the event.
+unsafe fn configure_event(event: usize, match_index: usize, high: bool) {
+    let mut ctrl = match_index as u32 | regs::COMBMODE_MATCH;
+    if high {
+        ctrl |= regs::HEVENT;
+    }
+
+    let ev = &regs::sct().ev[event];
+    ev.ev_state.write(|w| w.bits(0x1));
+    ev.ev_ctrl.write(|w| w.bits(ctrl));
+}
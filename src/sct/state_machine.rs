@@ -0,0 +1,639 @@
+//! Custom state machines for the SCT
+//!
+//! The SCT is built around a state machine. Events are triggered by match
+//! and input conditions, but only in the states they are enabled in. Each
+//! event can change the state, change outputs, control the counter, request
+//! DMA transfers, and trigger an interrupt.
+//!
+//! This module provides a [`Builder`] to define states and events, and load
+//! them into the SCT. The counter runs as a single 32-bit counter.
+//!
+//! The SCT supports 8 events and 8 states on both LPC82x and LPC845. LPC845
+//! has more match registers and outputs, however (see [`Match`] and
+//! [`Output`]).
+//!
+//! See user manual, section 16.7.
+//!
+//! # Example
+//!
+//! Toggle SCT_OUT0 every 1000 counts, and pulse SCT_OUT1 every other time.
+//!
+//! ``` no_run
+//! use lpc8xx_hal::{
+//!     prelude::*,
+//!     sct::state_machine::{Event, EventId, Match, Output, State},
+//!     Peripherals,
+//! };
+//!
+//! let p = Peripherals::take().unwrap();
+//!
+//! let mut syscon = p.SYSCON.split();
+//!
+//! let sct = p.SCT0.enable(&mut syscon.handle);
+//!
+//! let state_machine = sct
+//!     .state_machine()
+//!     .match_value(Match::Match0, 999)
+//!     .event(
+//!         EventId::Event0,
+//!         Event::on_match(Match::Match0)
+//!             .in_state(State::State0)
+//!             .toggle_output(Output::Output0)
+//!             .set_output(Output::Output1)
+//!             .set_state(State::State1)
+//!             .limit(),
+//!     )
+//!     .event(
+//!         EventId::Event1,
+//!         Event::on_match(Match::Match0)
+//!             .in_state(State::State1)
+//!             .toggle_output(Output::Output0)
+//!             .clear_output(Output::Output1)
+//!             .set_state(State::State0)
+//!             .limit(),
+//!     )
+//!     .start();
+//! ```
+//!
+//! [`Builder`]: struct.Builder.html
+//! [`Match`]: enum.Match.html
+//! [`Output`]: enum.Output.html
+
+use super::{regs, SCT};
+
+/// Builds a custom state machine
+///
+/// Use [`SCT::state_machine`] to get an instance of this struct.
+///
+/// [`SCT::state_machine`]: ../struct.SCT.html#method.state_machine
+pub struct Builder {
+    sct: SCT,
+    prescaler: u8,
+    initial_state: State,
+    initial_outputs: u32,
+    matches: [u32; regs::NUM_MATCHES],
+    events: [Option<Event>; regs::NUM_EVENTS],
+}
+
+impl Builder {
+    pub(super) fn new(sct: SCT) -> Self {
+        Builder {
+            sct,
+            prescaler: 0,
+            initial_state: State::State0,
+            initial_outputs: 0,
+            matches: [0; regs::NUM_MATCHES],
+            events: [None; regs::NUM_EVENTS],
+        }
+    }
+
+    /// Set the prescaler
+    ///
+    /// The SCT clock is the system clock divided by `prescaler + 1`. The
+    /// default is `0`.
+    pub fn prescaler(mut self, prescaler: u8) -> Self {
+        self.prescaler = prescaler;
+        self
+    }
+
+    /// Set the state the state machine starts in
+    ///
+    /// The default is [`State::State0`].
+    ///
+    /// [`State::State0`]: enum.State.html#variant.State0
+    pub fn initial_state(mut self, state: State) -> Self {
+        self.initial_state = state;
+        self
+    }
+
+    /// Set the level of an output, before the state machine starts
+    ///
+    /// All outputs are LOW by default.
+    pub fn initial_output(mut self, output: Output, high: bool) -> Self {
+        if high {
+            self.initial_outputs |= output.mask();
+        } else {
+            self.initial_outputs &= !output.mask();
+        }
+        self
+    }
+
+    /// Set the value of a match register
+    ///
+    /// All match registers are `0` by default. The value can be changed
+    /// while the state machine is running, using
+    /// [`StateMachine::set_match_value`].
+    ///
+    /// [`StateMachine::set_match_value`]: struct.StateMachine.html#method.set_match_value
+    pub fn match_value(mut self, match_: Match, value: u32) -> Self {
+        self.matches[match_ as usize] = value;
+        self
+    }
+
+    /// Define an event
+    ///
+    /// Replaces any previous definition of the same event. Events that are
+    /// not defined are never triggered.
+    pub fn event(mut self, id: EventId, event: Event) -> Self {
+        self.events[id as usize] = Some(event);
+        self
+    }
+
+    /// Load the state machine into the SCT and start it
+    ///
+    /// The counter starts at `0`.
+    pub fn start(mut self) -> StateMachine {
+        let mut out_set = [0; regs::NUM_OUTPUTS];
+        let mut out_clr = [0; regs::NUM_OUTPUTS];
+        let mut res = 0;
+        let mut limit = 0;
+        let mut halt = 0;
+        let mut stop = 0;
+        let mut start = 0;
+        let mut dmareq = [0; 2];
+        let mut even = 0;
+
+        self.sct.reset();
+        let registers = regs::sct();
+
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            // Synchronize all inputs, as they would be after a reset.
+            registers
+                .config
+                .write(|w| w.bits(regs::UNIFY | (0xf << regs::INSYNC_OFFSET)));
+
+            for (index, &value) in self.matches.iter().enumerate() {
+                regs::write(regs::indexed(regs::MATCH, 4, index), value);
+                regs::write(regs::indexed(regs::MATCHREL, 4, index), value);
+            }
+
+            for (index, event) in self.events.iter().enumerate() {
+                let bit = 0x1 << index;
+
+                // Events that are not defined stay disabled.
+                let event = match event {
+                    Some(event) => event,
+                    None => continue,
+                };
+
+                let ev = &registers.ev[index];
+                ev.ev_state.write(|w| w.bits(event.states));
+                ev.ev_ctrl.write(|w| w.bits(event.ctrl));
+
+                let outputs = out_set.iter_mut().zip(out_clr.iter_mut());
+                for (output, (out_set, out_clr)) in outputs.enumerate() {
+                    let mask = 0x1 << output;
+
+                    if event.set & mask != 0 {
+                        *out_set |= bit;
+                    }
+                    if event.clear & mask != 0 {
+                        *out_clr |= bit;
+                    }
+                    if event.toggle & mask != 0 {
+                        *out_set |= bit;
+                        *out_clr |= bit;
+                        res |= 0b11 << (output * 2);
+                    }
+                }
+
+                if event.limit {
+                    limit |= bit;
+                }
+                if event.halt {
+                    halt |= bit;
+                }
+                if event.stop {
+                    stop |= bit;
+                }
+                if event.start {
+                    start |= bit;
+                }
+                if event.interrupt {
+                    even |= bit;
+                }
+                for (request, dmareq) in dmareq.iter_mut().enumerate() {
+                    if event.dma & (0x1 << request) != 0 {
+                        *dmareq |= bit;
+                    }
+                }
+            }
+
+            let outputs = out_set.iter().zip(out_clr.iter());
+            for (out, (&out_set, &out_clr)) in registers.out.iter().zip(outputs)
+            {
+                out.out_set.write(|w| w.bits(out_set));
+                out.out_clr.write(|w| w.bits(out_clr));
+            }
+
+            registers.res.write(|w| w.bits(res));
+            registers.limit.write(|w| w.bits(limit));
+            registers.halt.write(|w| w.bits(halt));
+            registers.stop.write(|w| w.bits(stop));
+            registers.start.write(|w| w.bits(start));
+            registers.dmareq0.write(|w| w.bits(dmareq[0]));
+            registers.dmareq1.write(|w| w.bits(dmareq[1]));
+            registers.even.write(|w| w.bits(even));
+
+            registers.state.write(|w| w.bits(self.initial_state as u32));
+            registers.output.write(|w| w.bits(self.initial_outputs));
+
+            registers.ctrl.write(|w| {
+                w.bits(
+                    regs::CLRCTR_L
+                        | ((self.prescaler as u32) << regs::PRE_L_OFFSET),
+                )
+            });
+        }
+
+        StateMachine { sct: self.sct }
+    }
+}
+
+/// A custom state machine that is running on the SCT
+///
+/// Use [`Builder::start`] to get an instance of this struct.
+///
+/// [`Builder::start`]: struct.Builder.html#method.start
+pub struct StateMachine {
+    sct: SCT,
+}
+
+impl StateMachine {
+    /// Return the current state
+    pub fn state(&self) -> State {
+        State::from_index(regs::sct().state.read().bits() & 0x7)
+    }
+
+    /// Return the current value of the counter
+    pub fn count(&self) -> u32 {
+        regs::sct().count.read().bits()
+    }
+
+    /// Indicates whether the counter has been halted by an event
+    ///
+    /// Use [`resume`] to restart a halted counter.
+    ///
+    /// [`resume`]: #method.resume
+    pub fn is_halted(&self) -> bool {
+        regs::sct().ctrl.read().bits() & regs::HALT_L != 0
+    }
+
+    /// Restart the counter, after it has been halted by an event
+    ///
+    /// The state machine continues in its current state. If `clear_counter`
+    /// is `true`, the counter restarts from `0`, otherwise it continues from
+    /// its current value. Does nothing, if the counter is not halted.
+    ///
+    /// See user manual, section 16.6.
+    pub fn resume(&mut self, clear_counter: bool) {
+        let registers = regs::sct();
+
+        let ctrl = registers.ctrl.read().bits();
+        if ctrl & regs::HALT_L == 0 {
+            return;
+        }
+
+        // Sound, as we own the SCT, and all values are valid. The counter can
+        // only be cleared while it is halted.
+        unsafe {
+            if clear_counter {
+                registers.ctrl.write(|w| w.bits(ctrl | regs::CLRCTR_L));
+            }
+            registers.ctrl.write(|w| w.bits(ctrl & !regs::HALT_L));
+        }
+    }
+
+    /// Set the value of a match register
+    ///
+    /// The new value takes effect when the counter is limited, or reaches `0`.
+    pub fn set_match_value(&mut self, match_: Match, value: u32) {
+        // Sound, as we own the SCT, and all values are valid.
+        unsafe {
+            regs::write(
+                regs::indexed(regs::MATCHREL, 4, match_ as usize),
+                value,
+            );
+        }
+    }
+
+    /// Indicates whether an event has been triggered
+    ///
+    /// The flag is set regardless of whether the event's interrupt is
+    /// enabled. Use [`clear_event_flag`] to clear it.
+    ///
+    /// [`clear_event_flag`]: #method.clear_event_flag
+    pub fn event_flag(&self, id: EventId) -> bool {
+        regs::sct().evflag.read().bits() & (0x1 << id as u32) != 0
+    }
+
+    /// Clear an event's flag
+    ///
+    /// This also acknowledges the event's interrupt, if it is enabled.
+    pub fn clear_event_flag(&mut self, id: EventId) {
+        // Sound, as we own the SCT, and flags are cleared by writing `1`.
+        regs::sct()
+            .evflag
+            .write(|w| unsafe { w.bits(0x1 << id as u32) });
+    }
+
+    /// Stop the state machine
+    ///
+    /// Halts the counter and returns the SCT. The outputs keep their current
+    /// level.
+    pub fn stop(self) -> SCT {
+        // Sound, as we own the SCT.
+        regs::sct().ctrl.write(|w| unsafe { w.bits(regs::HALT_L) });
+        self.sct
+    }
+}
+
+/// Defines an event of the state machine
+///
+/// Create an instance using one of the constructors, which define the
+/// condition that triggers the event. Then use the other methods to define
+/// in which states the event is enabled, and what happens when it triggers.
+///
+/// An event that is not enabled in any state never triggers.
+#[derive(Clone, Copy, Debug)]
+pub struct Event {
+    ctrl: u32,
+    states: u32,
+    set: u32,
+    clear: u32,
+    toggle: u32,
+    limit: bool,
+    halt: bool,
+    stop: bool,
+    start: bool,
+    dma: u32,
+    interrupt: bool,
+}
+
+impl Event {
+    /// Trigger the event when the counter matches a match register
+    pub fn on_match(match_: Match) -> Self {
+        Self::new(match_ as u32 | regs::COMBMODE_MATCH)
+    }
+
+    /// Trigger the event when an input meets a condition
+    pub fn on_input(input: Input, condition: IoCondition) -> Self {
+        Self::new(Self::io(input, condition) | regs::COMBMODE_IO)
+    }
+
+    /// Trigger the event when either a match or an input condition occurs
+    pub fn on_match_or_input(
+        match_: Match,
+        input: Input,
+        condition: IoCondition,
+    ) -> Self {
+        Self::new(match_ as u32 | Self::io(input, condition))
+    }
+
+    /// Trigger the event when both a match and an input condition occur
+    pub fn on_match_and_input(
+        match_: Match,
+        input: Input,
+        condition: IoCondition,
+    ) -> Self {
+        Self::new(
+            match_ as u32 | Self::io(input, condition) | regs::COMBMODE_AND,
+        )
+    }
+
+    fn new(ctrl: u32) -> Self {
+        Event {
+            ctrl,
+            states: 0,
+            set: 0,
+            clear: 0,
+            toggle: 0,
+            limit: false,
+            halt: false,
+            stop: false,
+            start: false,
+            dma: 0,
+            interrupt: false,
+        }
+    }
+
+    fn io(input: Input, condition: IoCondition) -> u32 {
+        ((input as u32) << regs::IOSEL_OFFSET)
+            | ((condition as u32) << regs::IOCOND_OFFSET)
+    }
+
+    /// Enable the event in a state
+    ///
+    /// Can be called multiple times, to enable the event in multiple states.
+    pub fn in_state(mut self, state: State) -> Self {
+        self.states |= 0x1 << state as u32;
+        self
+    }
+
+    /// Enable the event in all states
+    pub fn in_all_states(mut self) -> Self {
+        self.states = 0xff;
+        self
+    }
+
+    /// Change to a state, when the event triggers
+    ///
+    /// The state doesn't change by default.
+    pub fn set_state(mut self, state: State) -> Self {
+        self.ctrl |= regs::STATELD | ((state as u32) << regs::STATEV_OFFSET);
+        self
+    }
+
+    /// Set an output HIGH, when the event triggers
+    pub fn set_output(mut self, output: Output) -> Self {
+        self.set |= output.mask();
+        self
+    }
+
+    /// Set an output LOW, when the event triggers
+    pub fn clear_output(mut self, output: Output) -> Self {
+        self.clear |= output.mask();
+        self
+    }
+
+    /// Toggle an output, when the event triggers
+    ///
+    /// Toggling is implemented using the SCT's conflict resolution. If
+    /// another event sets and clears the same output at the same time, the
+    /// output is also toggled.
+    pub fn toggle_output(mut self, output: Output) -> Self {
+        self.toggle |= output.mask();
+        self
+    }
+
+    /// Limit the counter, when the event triggers
+    ///
+    /// The counter is reset to `0`.
+    pub fn limit(mut self) -> Self {
+        self.limit = true;
+        self
+    }
+
+    /// Halt the counter, when the event triggers
+    ///
+    /// A halted counter can only be restarted by software, using
+    /// [`StateMachine::resume`].
+    ///
+    /// [`StateMachine::resume`]: struct.StateMachine.html#method.resume
+    pub fn halt(mut self) -> Self {
+        self.halt = true;
+        self
+    }
+
+    /// Stop the counter, when the event triggers
+    ///
+    /// Unlike a halted counter, a stopped counter can be restarted by
+    /// another event.
+    pub fn stop(mut self) -> Self {
+        self.stop = true;
+        self
+    }
+
+    /// Restart a stopped counter, when the event triggers
+    pub fn start(mut self) -> Self {
+        self.start = true;
+        self
+    }
+
+    /// Request a DMA transfer, when the event triggers
+    pub fn request_dma(mut self, request: DmaRequest) -> Self {
+        self.dma |= 0x1 << request as u32;
+        self
+    }
+
+    /// Trigger the SCT interrupt, when the event triggers
+    ///
+    /// Use [`StateMachine::clear_event_flag`] to acknowledge the interrupt.
+    ///
+    /// [`StateMachine::clear_event_flag`]: struct.StateMachine.html#method.clear_event_flag
+    pub fn interrupt(mut self) -> Self {
+        self.interrupt = true;
+        self
+    }
+}
+
+/// Identifies an event
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventId {
+    Event0 = 0,
+    Event1 = 1,
+    Event2 = 2,
+    Event3 = 3,
+    Event4 = 4,
+    Event5 = 5,
+    Event6 = 6,
+    Event7 = 7,
+}
+
+/// Identifies a state
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    State0 = 0,
+    State1 = 1,
+    State2 = 2,
+    State3 = 3,
+    State4 = 4,
+    State5 = 5,
+    State6 = 6,
+    State7 = 7,
+}
+
+impl State {
+    fn from_index(index: u32) -> Self {
+        match index {
+            0 => State::State0,
+            1 => State::State1,
+            2 => State::State2,
+            3 => State::State3,
+            4 => State::State4,
+            5 => State::State5,
+            6 => State::State6,
+            _ => State::State7,
+        }
+    }
+}
+
+/// Identifies a match register
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Match {
+    Match0 = 0,
+    Match1 = 1,
+    Match2 = 2,
+    Match3 = 3,
+    Match4 = 4,
+    #[cfg(feature = "845")]
+    Match5 = 5,
+    #[cfg(feature = "845")]
+    Match6 = 6,
+    #[cfg(feature = "845")]
+    Match7 = 7,
+}
+
+/// Identifies an SCT output
+///
+/// The outputs are available as the SCT_OUTn movable functions of the switch
+/// matrix.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Output {
+    Output0 = 0,
+    Output1 = 1,
+    Output2 = 2,
+    Output3 = 3,
+    Output4 = 4,
+    Output5 = 5,
+    #[cfg(feature = "845")]
+    Output6 = 6,
+}
+
+impl Output {
+    fn mask(self) -> u32 {
+        0x1 << self as u32
+    }
+}
+
+/// Identifies an SCT input
+///
+/// The inputs are available as the SCT_PINn movable functions of the switch
+/// matrix.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Input {
+    Input0 = 0,
+    Input1 = 1,
+    Input2 = 2,
+    Input3 = 3,
+}
+
+/// The condition an input must meet to trigger an event
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IoCondition {
+    /// The input is LOW
+    Low = 0,
+
+    /// The input has changed from LOW to HIGH
+    Rise = 1,
+
+    /// The input has changed from HIGH to LOW
+    Fall = 2,
+
+    /// The input is HIGH
+    High = 3,
+}
+
+/// Identifies one of the SCT's DMA requests
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DmaRequest {
+    Request0 = 0,
+    Request1 = 1,
+}
@@ -226,6 +226,7 @@ pub trait PinTrait {
 
 macro_rules! pins {
     ($(
+        $(#[$attr:meta])*
         $field:ident,
         $type:ident,
         $port:expr,
@@ -237,23 +238,32 @@ macro_rules! pins {
         ///
         /// This struct is a part of [`swm::Parts`].
         ///
-        /// # Limitations
+        /// # Package Selection
         ///
-        /// This struct currently provides access to all pins that can be
-        /// available on an LPC8xx part. Please make sure that you are aware of
-        /// which pins are actually available on your specific part, and only
-        /// use those.
+        /// Which pins are available depends on the package of the part. If a
+        /// package is selected using one of the target selection features
+        /// (like `845m301jhi33`), only the pins that exist in that package are
+        /// available, and code that uses any other pin will not compile.
+        ///
+        /// If only a family is selected (like `845`), all pins that exist in
+        /// any package of that family are available. Please make sure that you
+        /// are aware of which pins are actually available on your specific
+        /// part, and only use those.
         ///
         /// [`swm::Parts`]: struct.Parts.html
         #[allow(missing_docs)]
         pub struct Pins {
-            $(pub $field: Pin<$type, $default_state_ty>,)*
+            $(
+                $(#[$attr])*
+                pub $field: Pin<$type, $default_state_ty>,
+            )*
         }
 
         impl Pins {
             pub(crate) fn new() -> Self {
                 Pins {
                     $(
+                        $(#[$attr])*
                         $field: Pin {
                             ty   : $type(()),
                             state: $default_state_val,
@@ -270,9 +280,11 @@ macro_rules! pins {
             /// Pins can be accessed via the field `pins` of [`swm::Parts`].
             ///
             /// [`swm::Parts`]: struct.Parts.html
+            $(#[$attr])*
             #[allow(non_camel_case_types)]
             pub struct $type(());
 
+            $(#[$attr])*
             impl PinTrait for $type {
                 const PORT: usize = $port;
                 const ID  : u8    = $id;
@@ -300,18 +312,31 @@ pins!(
     pio0_13, PIO0_13, 0, 0x0d, pin_state::Unused        , pin_state::Unused;
     pio0_14, PIO0_14, 0, 0x0e, pin_state::Unused        , pin_state::Unused;
     pio0_15, PIO0_15, 0, 0x0f, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_16, PIO0_16, 0, 0x10, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_17, PIO0_17, 0, 0x11, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_18, PIO0_18, 0, 0x12, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_19, PIO0_19, 0, 0x13, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_20, PIO0_20, 0, 0x14, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_21, PIO0_21, 0, 0x15, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_22, PIO0_22, 0, 0x16, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_23, PIO0_23, 0, 0x17, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_24, PIO0_24, 0, 0x18, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_25, PIO0_25, 0, 0x19, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_26, PIO0_26, 0, 0x1a, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_27, PIO0_27, 0, 0x1b, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "20"))]
     pio0_28, PIO0_28, 0, 0x1c, pin_state::Unused        , pin_state::Unused;
 );
 
@@ -346,30 +371,55 @@ pins!(
     pio0_26, PIO0_26, 0, 0x1a, pin_state::Unused        , pin_state::Unused;
     pio0_27, PIO0_27, 0, 0x1b, pin_state::Unused        , pin_state::Unused;
     pio0_28, PIO0_28, 0, 0x1c, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio0_29, PIO0_29, 0, 0x1d, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio0_30, PIO0_30, 0, 0x1e, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio0_31, PIO0_31, 0, 0x1f, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_0 , PIO1_0 , 1, 0x00, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_1 , PIO1_1 , 1, 0x01, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_2 , PIO1_2 , 1, 0x02, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_3 , PIO1_3 , 1, 0x03, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_4 , PIO1_4 , 1, 0x04, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_5 , PIO1_5 , 1, 0x05, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_6 , PIO1_6 , 1, 0x06, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_7 , PIO1_7 , 1, 0x07, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_8 , PIO1_8 , 1, 0x08, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(feature = "33"))]
     pio1_9 , PIO1_9 , 1, 0x09, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_10, PIO1_10, 1, 0x0a, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_11, PIO1_11, 1, 0x0b, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_12, PIO1_12, 1, 0x0c, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_13, PIO1_13, 1, 0x0d, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_14, PIO1_14, 1, 0x0e, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_15, PIO1_15, 1, 0x0f, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_16, PIO1_16, 1, 0x10, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_17, PIO1_17, 1, 0x11, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_18, PIO1_18, 1, 0x12, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_19, PIO1_19, 1, 0x13, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_20, PIO1_20, 1, 0x14, pin_state::Unused        , pin_state::Unused;
+    #[cfg(not(any(feature = "33", feature = "48")))]
     pio1_21, PIO1_21, 1, 0x15, pin_state::Unused        , pin_state::Unused;
 );
 
@@ -613,6 +663,9 @@ where
             pin: slice::from_ref(&gpio.gpio.pin0),
             set: slice::from_ref(&gpio.gpio.set0),
             clr: slice::from_ref(&gpio.gpio.clr0),
+            not: slice::from_ref(&gpio.gpio.not0),
+            b: &gpio.gpio.b0,
+            w: &gpio.gpio.w0,
         };
         #[cfg(feature = "845")]
         let registers = pin_state::GpioRegisters {
@@ -621,6 +674,9 @@ where
             pin: &gpio.gpio.pin,
             set: &gpio.gpio.set,
             clr: &gpio.gpio.clr,
+            not: &gpio.gpio.not,
+            b: &gpio.gpio.b,
+            w: &gpio.gpio.w,
         };
         Pin {
             ty: self.ty,
@@ -778,11 +834,11 @@ pub mod pin_state {
 
     use crate::gpio::direction::Direction;
     #[cfg(feature = "845")]
-    use crate::pac::gpio::{CLR, DIRCLR, DIRSET, PIN, SET};
+    use crate::pac::gpio::{B, CLR, DIRCLR, DIRSET, NOT, PIN, SET, W};
     #[cfg(feature = "82x")]
     use crate::pac::gpio::{
-        CLR0 as CLR, DIRCLR0 as DIRCLR, DIRSET0 as DIRSET, PIN0 as PIN,
-        SET0 as SET,
+        B0 as B, CLR0 as CLR, DIRCLR0 as DIRCLR, DIRSET0 as DIRSET,
+        NOT0 as NOT, PIN0 as PIN, SET0 as SET, W0 as W,
     };
 
     /// Implemented by types that indicate pin state
@@ -823,6 +879,9 @@ pub mod pin_state {
         pub(crate) pin: &'gpio [PIN],
         pub(crate) set: &'gpio [SET],
         pub(crate) clr: &'gpio [CLR],
+        pub(crate) not: &'gpio [NOT],
+        pub(crate) b: &'gpio [B],
+        pub(crate) w: &'gpio [W],
     }
 
     impl<'gpio, D> PinState for Gpio<'gpio, D> where D: Direction {}
@@ -1147,44 +1206,44 @@ macro_rules! movable_functions {
             impl_function!($type, $kind, $reg_name, $reg_field, PIO0_13);
             impl_function!($type, $kind, $reg_name, $reg_field, PIO0_14);
             impl_function!($type, $kind, $reg_name, $reg_field, PIO0_15);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_16);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_17);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_18);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_19);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_20);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_21);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_22);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_23);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_24);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_25);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_26);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_27);
-            impl_function!($type, $kind, $reg_name, $reg_field, PIO0_28);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_29);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_30);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_31);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_0 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_1 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_2 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_3 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_4 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_5 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_6 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_7 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_8 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_9 );
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_10);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_11);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_12);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_13);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_14);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_15);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_16);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_17);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_18);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_19);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_20);
-            #[cfg(feature = "845")] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_21);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_16);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_17);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_18);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_19);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_20);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_21);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_22);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_23);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_24);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_25);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_26);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_27);
+            #[cfg(not(feature = "20"))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_28);
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_29);
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_30);
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO0_31);
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_0 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_1 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_2 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_3 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_4 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_5 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_6 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_7 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_8 );
+            #[cfg(all(feature = "845", not(feature = "33")))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_9 );
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_10);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_11);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_12);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_13);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_14);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_15);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_16);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_17);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_18);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_19);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_20);
+            #[cfg(all(feature = "845", not(any(feature = "33", feature = "48"))))] impl_function!($type, $kind, $reg_name, $reg_field, PIO1_21);
         )*
     }
 }
@@ -1333,6 +1392,7 @@ movable_functions!(
 
 macro_rules! fixed_functions {
     ($(
+        $(#[$attr:meta])*
         $type:ident,
         $kind:ident,
         $register:ident,
@@ -1347,16 +1407,22 @@ macro_rules! fixed_functions {
         /// [`swm::Parts`]: struct.Parts.html
         #[allow(missing_docs)]
         pub struct FixedFunctions {
-            $(pub $field: Function<$type, $default_state>,)*
+            $(
+                $(#[$attr])*
+                pub $field: Function<$type, $default_state>,
+            )*
         }
 
         impl FixedFunctions {
             fn new() -> Self {
                 FixedFunctions {
-                    $($field: Function {
-                        ty    : $type(()),
-                        _state: state::State::new(),
-                    },)*
+                    $(
+                        $(#[$attr])*
+                        $field: Function {
+                            ty    : $type(()),
+                            _state: state::State::new(),
+                        },
+                    )*
                 }
             }
         }
@@ -1369,9 +1435,11 @@ macro_rules! fixed_functions {
             /// of [`swm::Parts`].
             ///
             /// [`swm::Parts`]: struct.Parts.html
+            $(#[$attr])*
             #[allow(non_camel_case_types)]
             pub struct $type(());
 
+            $(#[$attr])*
             impl FunctionTrait<$pin> for $type {
                 type Kind = $kind;
 
@@ -1394,6 +1462,7 @@ fixed_functions!(
     ACMP_I1 , Input , pinenable0, acmp_i1 , PIO0_0 , state::Unassigned;
     ACMP_I2 , Input , pinenable0, acmp_i2 , PIO0_1 , state::Unassigned;
     ACMP_I3 , Input , pinenable0, acmp_i3 , PIO0_14, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ACMP_I4 , Input , pinenable0, acmp_i4 , PIO0_23, state::Unassigned;
     SWCLK   , Output, pinenable0, swclk   , PIO0_3 , state::Assigned<PIO0_3>;
     SWDIO   , Output, pinenable0, swdio   , PIO0_2 , state::Assigned<PIO0_2>;
@@ -1407,12 +1476,19 @@ fixed_functions!(
     ADC_0   , Analog, pinenable0, adc_0   , PIO0_7 , state::Unassigned;
     ADC_1   , Analog, pinenable0, adc_1   , PIO0_6 , state::Unassigned;
     ADC_2   , Analog, pinenable0, adc_2   , PIO0_14, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_3   , Analog, pinenable0, adc_3   , PIO0_23, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_4   , Analog, pinenable0, adc_4   , PIO0_22, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_5   , Analog, pinenable0, adc_5   , PIO0_21, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_6   , Analog, pinenable0, adc_6   , PIO0_20, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_7   , Analog, pinenable0, adc_7   , PIO0_19, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_8   , Analog, pinenable0, adc_8   , PIO0_18, state::Unassigned;
+    #[cfg(not(feature = "20"))]
     ADC_9   , Analog, pinenable0, adc_9   , PIO0_17, state::Unassigned;
     ADC_10  , Analog, pinenable0, adc_10  , PIO0_13, state::Unassigned;
     ADC_11  , Analog, pinenable0, adc_11  , PIO0_4 , state::Unassigned;
@@ -1446,17 +1522,29 @@ fixed_functions!(
     ADC_10  , Analog, pinenable0, adc_10  , PIO0_13, state::Unassigned;
     ADC_11  , Analog, pinenable0, adc_11  , PIO0_4 , state::Unassigned;
     DACOUT0 , Analog, pinenable0, dacout0 , PIO0_17, state::Unassigned;
+    #[cfg(not(feature = "33"))]
     DACOUT1 , Analog, pinenable0, dacout1 , PIO0_29, state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X0 , Analog, pinenable0, capt_x0 , PIO0_31, state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X1 , Analog, pinenable0, capt_x1 , PIO1_0 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X2 , Analog, pinenable0, capt_x2 , PIO1_1 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X3 , Analog, pinenable0, capt_x3 , PIO1_2 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X4 , Analog, pinenable1, capt_x4 , PIO1_3 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X5 , Analog, pinenable1, capt_x5 , PIO1_4 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X6 , Analog, pinenable1, capt_x6 , PIO1_5 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X7 , Analog, pinenable1, capt_x7 , PIO1_6 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_X8 , Analog, pinenable1, capt_x8 , PIO1_7 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_YL , Analog, pinenable1, capt_yl , PIO1_8 , state::Unassigned;
+    #[cfg(not(feature = "33"))]
     CAPT_YH , Analog, pinenable1, capt_yh , PIO1_8 , state::Unassigned;
 );
 
@@ -31,15 +31,25 @@ use core::marker::PhantomData;
 #[cfg(feature = "82x")]
 use crate::pac::syscon::{
     pdruncfg, presetctrl as presetctrl0, starterp1,
-    sysahbclkctrl as sysahbclkctrl0, PDRUNCFG, PRESETCTRL as PRESETCTRL0,
-    STARTERP1, SYSAHBCLKCTRL as SYSAHBCLKCTRL0, UARTCLKDIV, UARTFRGDIV,
-    UARTFRGMULT,
+    sysahbclkctrl as sysahbclkctrl0, PDAWAKECFG, PDRUNCFG,
+    PRESETCTRL as PRESETCTRL0, STARTERP0, STARTERP1,
+    SYSAHBCLKCTRL as SYSAHBCLKCTRL0, UARTCLKDIV, UARTFRGDIV, UARTFRGMULT,
 };
 
 #[cfg(feature = "845")]
 use crate::pac::syscon::{
-    pdruncfg, presetctrl0, starterp1, sysahbclkctrl0, FCLKSEL, PDRUNCFG,
-    PRESETCTRL0, STARTERP1, SYSAHBCLKCTRL0,
+    pdruncfg, presetctrl0, starterp1, sysahbclkctrl0, FCLKSEL, PDAWAKECFG,
+    PDRUNCFG, PRESETCTRL0, STARTERP0, STARTERP1, SYSAHBCLKCTRL0,
+};
+
+#[cfg(feature = "845")]
+use crate::pac::syscon::{MAINCLKPLLSEL, MAINCLKPLLUEN};
+
+use crate::pac::syscon::{MAINCLKSEL, MAINCLKUEN, SYSPLLSTAT};
+
+use crate::pac::syscon::{
+    IOCONCLKDIV0, IOCONCLKDIV1, IOCONCLKDIV2, IOCONCLKDIV3, IOCONCLKDIV4,
+    IOCONCLKDIV5, IOCONCLKDIV6,
 };
 
 use crate::{clock, init_state, pac, reg_proxy::RegProxy};
@@ -78,10 +88,19 @@ impl SYSCON {
     pub fn split(self) -> Parts {
         Parts {
             handle: Handle {
+                mainclksel: RegProxy::new(),
+                mainclkuen: RegProxy::new(),
+                #[cfg(feature = "845")]
+                mainclkpllsel: RegProxy::new(),
+                #[cfg(feature = "845")]
+                mainclkplluen: RegProxy::new(),
+                pdawakecfg: RegProxy::new(),
                 pdruncfg: RegProxy::new(),
                 presetctrl0: RegProxy::new(),
+                starterp0: RegProxy::new(),
                 starterp1: RegProxy::new(),
                 sysahbclkctrl: RegProxy::new(),
+                syspllstat: RegProxy::new(),
                 #[cfg(feature = "845")]
                 fclksel: RegProxy::new(),
             },
@@ -104,6 +123,13 @@ impl SYSCON {
             },
 
             iosc_derived_clock: IoscDerivedClock::new(),
+            ioconclkdiv0: IoconClkDiv0::new(),
+            ioconclkdiv1: IoconClkDiv1::new(),
+            ioconclkdiv2: IoconClkDiv2::new(),
+            ioconclkdiv3: IoconClkDiv3::new(),
+            ioconclkdiv4: IoconClkDiv4::new(),
+            ioconclkdiv5: IoconClkDiv5::new(),
+            ioconclkdiv6: IoconClkDiv6::new(),
             #[cfg(feature = "845")]
             frg0: FRG::new(),
             #[cfg(feature = "845")]
@@ -172,6 +198,27 @@ pub struct Parts {
     /// The 750 kHz internal oscillator/IRC/FRO-derived clock
     pub iosc_derived_clock: IoscDerivedClock<init_state::Enabled>,
 
+    /// IOCON glitch filter clock divider 0
+    pub ioconclkdiv0: IoconClkDiv0,
+
+    /// IOCON glitch filter clock divider 1
+    pub ioconclkdiv1: IoconClkDiv1,
+
+    /// IOCON glitch filter clock divider 2
+    pub ioconclkdiv2: IoconClkDiv2,
+
+    /// IOCON glitch filter clock divider 3
+    pub ioconclkdiv3: IoconClkDiv3,
+
+    /// IOCON glitch filter clock divider 4
+    pub ioconclkdiv4: IoconClkDiv4,
+
+    /// IOCON glitch filter clock divider 5
+    pub ioconclkdiv5: IoconClkDiv5,
+
+    /// IOCON glitch filter clock divider 6
+    pub ioconclkdiv6: IoconClkDiv6,
+
     #[cfg(feature = "845")]
     /// Fractional Baud Rate Generator 0
     pub frg0: FRG<frg::FRG0>,
@@ -192,10 +239,19 @@ pub struct Parts {
 ///
 /// [module documentation]: index.html
 pub struct Handle {
+    mainclksel: RegProxy<MAINCLKSEL>,
+    mainclkuen: RegProxy<MAINCLKUEN>,
+    #[cfg(feature = "845")]
+    mainclkpllsel: RegProxy<MAINCLKPLLSEL>,
+    #[cfg(feature = "845")]
+    mainclkplluen: RegProxy<MAINCLKPLLUEN>,
+    pdawakecfg: RegProxy<PDAWAKECFG>,
     pdruncfg: RegProxy<PDRUNCFG>,
     presetctrl0: RegProxy<PRESETCTRL0>,
+    pub(crate) starterp0: RegProxy<STARTERP0>,
     starterp1: RegProxy<STARTERP1>,
     sysahbclkctrl: RegProxy<SYSAHBCLKCTRL0>,
+    syspllstat: RegProxy<SYSPLLSTAT>,
     #[cfg(feature = "845")]
     pub(crate) fclksel: RegProxy<FCLKSEL>,
 }
@@ -243,6 +299,20 @@ impl Handle {
         self.pdruncfg.modify(|_, w| peripheral.power_down(w));
     }
 
+    /// Keep the current power configuration after waking up
+    ///
+    /// When waking up from deep-sleep or power-down mode, the contents of
+    /// PDAWAKECFG are loaded into PDRUNCFG. This method copies the current
+    /// contents of PDRUNCFG into PDAWAKECFG, which makes sure that all analog
+    /// blocks that are currently powered (including the oscillators that the
+    /// system clock might depend on) are powered again after waking up.
+    ///
+    /// See user manual, section 5.6.34.
+    pub fn retain_power_config_on_wakeup(&mut self) {
+        let pdruncfg = self.pdruncfg.read().bits();
+        self.pdawakecfg.write(|w| unsafe { w.bits(pdruncfg) });
+    }
+
     /// Enable interrupt wake-up from deep-sleep and power-down modes
     ///
     /// To use an interrupt for waking up the system from the deep-sleep and
@@ -264,8 +334,117 @@ impl Handle {
     {
         self.starterp1.modify(|_, w| I::disable(w));
     }
+
+    /// Indicates whether interrupt wake-up is enabled for the interrupt
+    pub fn is_interrupt_wakeup_enabled<I>(&self) -> bool
+    where
+        I: WakeUpInterrupt,
+    {
+        I::is_enabled(&self.starterp1.read())
+    }
+
+    /// Select the IRC/FRO as the main clock
+    ///
+    /// The IRC/FRO must be the main clock when entering deep-sleep or
+    /// power-down mode. Powers up the IRC/FRO and its output first, if
+    /// necessary. Returns the previous selection, which can be restored using
+    /// [`restore_main_clock`].
+    ///
+    /// See user manual, section 6.7.5.2.
+    ///
+    /// [`restore_main_clock`]: #method.restore_main_clock
+    pub(crate) fn select_irc_main_clock(&mut self) -> MainClock {
+        let main_clock = MainClock {
+            sel: self.mainclksel.read().bits(),
+            #[cfg(feature = "845")]
+            pllsel: self.mainclkpllsel.read().bits(),
+            irc_pd: self.pdruncfg.read().bits() & IRC_PD,
+        };
+
+        // Sound, as only the power-down bits of the IRC/FRO are cleared.
+        self.pdruncfg
+            .modify(|r, w| unsafe { w.bits(r.bits() & !IRC_PD) });
+
+        // Sound, as `0` selects the IRC/FRO, which is powered up now.
+        unsafe {
+            #[cfg(feature = "845")]
+            self.set_main_clock_pll(0);
+            self.set_main_clock(0);
+        }
+
+        main_clock
+    }
+
+    /// Restore a main clock selection
+    ///
+    /// If the selection includes the system PLL, waits for it to lock first,
+    /// as the PLL loses its lock in deep-sleep mode. Powers down the IRC/FRO
+    /// again, if it was powered down before.
+    pub(crate) fn restore_main_clock(&mut self, main_clock: MainClock) {
+        if main_clock.uses_pll() {
+            while self.syspllstat.read().lock().bit_is_clear() {}
+        }
+
+        // Sound, as the selection has been read from the same registers.
+        unsafe {
+            self.set_main_clock(main_clock.sel);
+            #[cfg(feature = "845")]
+            self.set_main_clock_pll(main_clock.pllsel);
+
+            self.pdruncfg
+                .modify(|r, w| w.bits(r.bits() | main_clock.irc_pd));
+        }
+    }
+
+    /// Write MAINCLKSEL and latch the new value
+    ///
+    /// Unsafe, as `sel` must be a valid selection.
+    unsafe fn set_main_clock(&mut self, sel: u32) {
+        self.mainclksel.write(|w| w.bits(sel));
+        self.mainclkuen.write(|w| w.bits(0));
+        self.mainclkuen.write(|w| w.bits(1));
+    }
+
+    /// Write MAINCLKPLLSEL and latch the new value
+    ///
+    /// Unsafe, as `sel` must be a valid selection.
+    #[cfg(feature = "845")]
+    unsafe fn set_main_clock_pll(&mut self, sel: u32) {
+        self.mainclkpllsel.write(|w| w.bits(sel));
+        self.mainclkplluen.write(|w| w.bits(0));
+        self.mainclkplluen.write(|w| w.bits(1));
+    }
+}
+
+/// A main clock selection
+///
+/// Returned by `Handle::select_irc_main_clock`.
+pub(crate) struct MainClock {
+    sel: u32,
+    #[cfg(feature = "845")]
+    pllsel: u32,
+    irc_pd: u32,
+}
+
+impl MainClock {
+    /// Indicates whether the system PLL is selected
+    #[cfg(feature = "82x")]
+    fn uses_pll(&self) -> bool {
+        // PLL output
+        self.sel == 0x3
+    }
+
+    /// Indicates whether the system PLL is selected
+    #[cfg(feature = "845")]
+    fn uses_pll(&self) -> bool {
+        // sys_pll0_clk
+        self.pllsel == 0x1
+    }
 }
 
+/// The power-down bits of the IRC/FRO output and the IRC/FRO in PDRUNCFG
+const IRC_PD: u32 = 0x3;
+
 /// Brown-out detection
 ///
 /// Can be used to control brown-out detection using various methods on
@@ -372,8 +551,110 @@ impl UARTFRG {
     pub fn set_frgdiv(&mut self, value: u8) {
         self.uartfrgdiv.write(|w| unsafe { w.div().bits(value) });
     }
+
+    /// Configure U_PCLK for a set of baud rates
+    ///
+    /// `main_clock` is the frequency of the main clock in Hz. Computes the
+    /// values of UARTCLKDIV and UARTFRGMULT that allow all of the given baud
+    /// rates to be generated with the lowest error, and writes them to the
+    /// registers. UARTFRGDIV is set to `0xff`, as required by the user manual,
+    /// section 13.3.1.
+    ///
+    /// Since U_PCLK is shared between all USARTs, all baud rates that will be
+    /// used at the same time need to be passed to this method at once. The
+    /// returned [`UPclk`] can then be passed to
+    /// [`UsartClock::from_u_pclk`] for each of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `baud_rates` is empty, or if any of the baud rates can't be
+    /// generated from the main clock.
+    ///
+    /// [`UPclk`]: clocksource/struct.UPclk.html
+    /// [`UsartClock::from_u_pclk`]: clocksource/struct.UsartClock.html#method.from_u_pclk
+    pub fn set_baud_rates(
+        &mut self,
+        main_clock: u32,
+        baud_rates: &[u32],
+    ) -> clocksource::UPclk {
+        let (clkdiv, frgmult, u_pclk) =
+            clocksource::uartfrg_config(main_clock, baud_rates);
+
+        self.set_clkdiv(clkdiv);
+        self.set_frgmult(frgmult);
+        self.set_frgdiv(0xff);
+
+        u_pclk
+    }
+}
+
+/// Implemented by the clock dividers for the IOCON glitch filters
+///
+/// The digital input filter of each pin is clocked by one of seven clock
+/// dividers, which divide the main clock. Pass a type that implements this
+/// trait to [`Pin::set_input_filter`] to select the divider.
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+///
+/// [`Pin::set_input_filter`]: ../swm/struct.Pin.html#method.set_input_filter
+pub trait FilterClock {
+    /// The value of the CLK_DIV field in the IOCON registers
+    const INDEX: u8;
+}
+
+macro_rules! ioconclkdivs {
+    ($($name:ident, $reg:ident, $field:ident, $index:expr;)*) => {
+        $(
+            /// A clock divider for the IOCON glitch filters (IOCONCLKDIV)
+            ///
+            /// Divides the main clock, to provide the sample clock for the
+            /// digital input filters of pins. The divider is disabled on
+            /// reset, which means filters that use it won't see any changes
+            /// on their input, until a divider value is set.
+            ///
+            /// See user manual, section 5.6.
+            pub struct $name {
+                reg: RegProxy<$reg>,
+            }
+
+            impl $name {
+                fn new() -> Self {
+                    $name {
+                        reg: RegProxy::new(),
+                    }
+                }
+
+                /// Set the divider value
+                ///
+                /// The main clock is divided by `div`. A value of `0` disables
+                /// the clock.
+                pub fn set_div(&mut self, div: u8) {
+                    // Safe, as all `u8` values are valid.
+                    self.reg.write(|w| unsafe { w.bits(div.into()) });
+                }
+            }
+
+            impl FilterClock for $name {
+                const INDEX: u8 = $index;
+            }
+
+            reg!($reg, $reg, pac::SYSCON, $field);
+        )*
+    };
 }
 
+ioconclkdivs!(
+    IoconClkDiv0, IOCONCLKDIV0, ioconclkdiv0, 0;
+    IoconClkDiv1, IOCONCLKDIV1, ioconclkdiv1, 1;
+    IoconClkDiv2, IOCONCLKDIV2, ioconclkdiv2, 2;
+    IoconClkDiv3, IOCONCLKDIV3, ioconclkdiv3, 3;
+    IoconClkDiv4, IOCONCLKDIV4, ioconclkdiv4, 4;
+    IoconClkDiv5, IOCONCLKDIV5, ioconclkdiv5, 5;
+    IoconClkDiv6, IOCONCLKDIV6, ioconclkdiv6, 6;
+);
+
 /// Internal trait for controlling peripheral clocks
 ///
 /// This trait is an internal implementation detail and should neither be
@@ -455,6 +736,8 @@ impl_clock_control!(pac::ADC0, adc);
 impl_clock_control!(MTB, mtb);
 impl_clock_control!(pac::DMA0, dma);
 #[cfg(feature = "845")]
+impl_clock_control!(pac::PINT, gpio_int);
+#[cfg(feature = "845")]
 impl ClockControl for pac::GPIO {
     fn enable_clock<'w>(
         &self,
@@ -542,6 +825,8 @@ impl_reset_control!(pac::I2C2, i2c2_rst_n);
 impl_reset_control!(pac::I2C3, i2c3_rst_n);
 impl_reset_control!(pac::ADC0, adc_rst_n);
 impl_reset_control!(pac::DMA0, dma_rst_n);
+#[cfg(feature = "845")]
+impl_reset_control!(pac::PINT, gpioint_rst_n);
 
 #[cfg(feature = "845")]
 impl<'a> ResetControl for pac::GPIO {
@@ -692,6 +977,9 @@ pub trait WakeUpInterrupt {
 
     /// Internal method to configure interrupt wakeup behavior
     fn disable(w: &mut starterp1::W) -> &mut starterp1::W;
+
+    /// Internal method to read interrupt wakeup behavior
+    fn is_enabled(r: &starterp1::R) -> bool;
 }
 
 macro_rules! wakeup_interrupt {
@@ -713,6 +1001,10 @@ macro_rules! wakeup_interrupt {
             fn disable(w: &mut starterp1::W) -> &mut starterp1::W {
                 w.$field().disabled()
             }
+
+            fn is_enabled(r: &starterp1::R) -> bool {
+                r.$field().is_enabled()
+            }
         }
     };
 }
@@ -741,12 +1033,21 @@ pub trait PeripheralClock<PERIPH> {
     fn select_clock(&self, handle: &mut Handle);
 }
 
+reg!(MAINCLKSEL, MAINCLKSEL, pac::SYSCON, mainclksel);
+reg!(MAINCLKUEN, MAINCLKUEN, pac::SYSCON, mainclkuen);
+#[cfg(feature = "845")]
+reg!(MAINCLKPLLSEL, MAINCLKPLLSEL, pac::SYSCON, mainclkpllsel);
+#[cfg(feature = "845")]
+reg!(MAINCLKPLLUEN, MAINCLKPLLUEN, pac::SYSCON, mainclkplluen);
+reg!(PDAWAKECFG, PDAWAKECFG, pac::SYSCON, pdawakecfg);
 reg!(PDRUNCFG, PDRUNCFG, pac::SYSCON, pdruncfg);
 #[cfg(feature = "82x")]
 reg!(PRESETCTRL0, PRESETCTRL0, pac::SYSCON, presetctrl);
 #[cfg(feature = "845")]
 reg!(PRESETCTRL0, PRESETCTRL0, pac::SYSCON, presetctrl0);
+reg!(STARTERP0, STARTERP0, pac::SYSCON, starterp0);
 reg!(STARTERP1, STARTERP1, pac::SYSCON, starterp1);
+reg!(SYSPLLSTAT, SYSPLLSTAT, pac::SYSCON, syspllstat);
 #[cfg(feature = "82x")]
 reg!(SYSAHBCLKCTRL0, SYSAHBCLKCTRL0, pac::SYSCON, sysahbclkctrl);
 #[cfg(feature = "845")]
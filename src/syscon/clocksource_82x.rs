@@ -1,6 +1,151 @@
-use crate::syscon::{self, PeripheralClock, UARTFRG};
+use crate::{
+    clock,
+    syscon::{self, PeripheralClock, UARTFRG},
+};
 use core::marker::PhantomData;
 
+/// The common UART peripheral clock (U_PCLK)
+///
+/// Returned by [`UARTFRG::set_baud_rates`]. Can be used to create a
+/// [`UsartClock`] for one of the baud rates that were passed to that method,
+/// using [`UsartClock::from_u_pclk`].
+///
+/// [`UARTFRG::set_baud_rates`]: ../struct.UARTFRG.html#method.set_baud_rates
+/// [`UsartClock`]: struct.UsartClock.html
+/// [`UsartClock::from_u_pclk`]: struct.UsartClock.html#method.from_u_pclk
+#[derive(Clone, Copy, Debug)]
+pub struct UPclk {
+    hz: u32,
+}
+
+impl clock::Frequency for UPclk {
+    fn hz(&self) -> u32 {
+        self.hz
+    }
+}
+
+/// The deviation of an achieved baud rate from the desired baud rate
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BaudRateError {
+    /// The baud rate that is actually achieved
+    pub actual: u32,
+
+    /// The deviation from the desired baud rate in parts per million
+    ///
+    /// A positive value means the actual baud rate is higher than the desired
+    /// one.
+    pub ppm: i32,
+}
+
+impl BaudRateError {
+    fn new(desired: u32, actual: u32) -> Self {
+        let ppm = (actual as i64 - desired as i64) * 1_000_000 / desired as i64;
+
+        Self {
+            actual,
+            ppm: ppm as i32,
+        }
+    }
+}
+
+/// Computes the UART FRG configuration for a set of baud rates
+///
+/// Returns the values for UARTCLKDIV and UARTFRGMULT (UARTFRGDIV is assumed to
+/// be `0xff`), as well as the resulting U_PCLK.
+pub(crate) fn uartfrg_config(
+    main_clock: u32,
+    baud_rates: &[u32],
+) -> (u8, u8, UPclk) {
+    assert!(!baud_rates.is_empty());
+
+    let max_baud_rate = *baud_rates.iter().max().unwrap();
+    assert!(max_baud_rate > 0 && max_baud_rate as u64 * 5 <= main_clock as u64);
+
+    let mut best: Option<(u8, u8, UPclk, u32)> = None;
+
+    // The fractional generator divides the output of UARTCLKDIV by a value
+    // between 1 and 2. For each value of UARTCLKDIV, we aim for a U_PCLK that
+    // is an integer multiple of the highest baud rate times an oversampling
+    // value, as that is the most demanding one to hit. The other baud rates are
+    // evaluated with whatever U_PCLK that results in.
+    for clkdiv in 1..=255u32 {
+        let base = main_clock / clkdiv;
+        if base < max_baud_rate * 5 {
+            break;
+        }
+
+        for osr in (5..=16u32).rev() {
+            let step = max_baud_rate * osr;
+            let multiple = base / step;
+            if multiple == 0 {
+                continue;
+            }
+            let target = step as u64 * multiple as u64;
+
+            // U_PCLK = main_clock / (clkdiv * (1 + mult / 256))
+            let divisor = clkdiv as u64 * target;
+            let frgmult = (main_clock as u64 * 256 + divisor / 2) / divisor;
+            let frgmult = (frgmult.max(256) - 256).min(255);
+
+            let u_pclk =
+                main_clock as u64 * 256 / (clkdiv as u64 * (256 + frgmult));
+            let u_pclk = UPclk { hz: u_pclk as u32 };
+
+            let worst = baud_rates
+                .iter()
+                .map(|&baud_rate| {
+                    let (_, _, error) = usart_divider(u_pclk, baud_rate);
+                    error.ppm.abs() as u32
+                })
+                .max()
+                .unwrap();
+
+            let is_better = match best {
+                Some((_, _, _, best_worst)) => worst < best_worst,
+                None => true,
+            };
+            if is_better {
+                best = Some((clkdiv as u8, frgmult as u8, u_pclk, worst));
+            }
+            if worst == 0 {
+                break;
+            }
+        }
+    }
+
+    let (clkdiv, frgmult, u_pclk, _) = best.unwrap();
+    (clkdiv, frgmult, u_pclk)
+}
+
+/// Computes the BRG and OSR values for a baud rate, given U_PCLK
+///
+/// Returns the BRG value, the oversampling value (between 5 and 16), and the
+/// error of the resulting baud rate.
+fn usart_divider(u_pclk: UPclk, baud_rate: u32) -> (u16, u8, BaudRateError) {
+    let mut best: Option<(u16, u8, BaudRateError)> = None;
+
+    // Higher oversampling values are preferred, as they make the receiver more
+    // robust. Therefore we only replace a result if we find a better one.
+    for osr in (5..=16u32).rev() {
+        let step = baud_rate as u64 * osr as u64;
+        let divider = (u_pclk.hz as u64 + step / 2) / step;
+        let divider = divider.max(1).min(0x1_0000);
+
+        let actual = u_pclk.hz as u64 / (osr as u64 * divider);
+        let error = BaudRateError::new(baud_rate, actual as u32);
+
+        let is_better = match best {
+            Some((_, _, best_error)) => error.ppm.abs() < best_error.ppm.abs(),
+            None => true,
+        };
+        if is_better {
+            best = Some(((divider - 1) as u16, osr as u8, error));
+        }
+    }
+
+    best.unwrap()
+}
+
 /// Defines the clock configuration for a usart
 pub struct UsartClock<PeriphClock> {
     pub(crate) psc: u16,
@@ -22,6 +167,27 @@ impl<PERIPH: crate::usart::Instance> UsartClock<PERIPH> {
             _periphclock: PhantomData,
         }
     }
+
+    /// Create the clock config for the uart from U_PCLK and a baud rate
+    ///
+    /// `u_pclk` is returned by [`UARTFRG::set_baud_rates`], which must have
+    /// been passed `baudrate` (or a baud rate that can be generated from the
+    /// same U_PCLK). Computes the values of BRG and OSR that result in the
+    /// lowest error, and returns the clock configuration along with the error
+    /// that was achieved.
+    ///
+    /// [`UARTFRG::set_baud_rates`]: ../struct.UARTFRG.html#method.set_baud_rates
+    pub fn from_u_pclk(u_pclk: &UPclk, baudrate: u32) -> (Self, BaudRateError) {
+        let (psc, osrval, error) = usart_divider(*u_pclk, baudrate);
+
+        let clock = Self {
+            psc,
+            osrval: osrval - 1,
+            _periphclock: PhantomData,
+        };
+
+        (clock, error)
+    }
 }
 
 impl<USART: crate::usart::Instance> PeripheralClock<USART>
@@ -75,3 +241,58 @@ impl<PERIPH: crate::i2c::Instance> PeripheralClock<PERIPH>
         // NOOP, selected by default
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{uartfrg_config, usart_divider, BaudRateError, UPclk};
+
+    #[test]
+    fn usart_divider_should_hit_exact_baud_rate() {
+        let u_pclk = UPclk { hz: 11_059_200 };
+
+        let (brg, osr, error) = usart_divider(u_pclk, 115_200);
+
+        assert_eq!(brg, 5);
+        assert_eq!(osr, 16);
+        assert_eq!(
+            error,
+            BaudRateError {
+                actual: 115_200,
+                ppm: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn usart_divider_should_report_error() {
+        let u_pclk = UPclk { hz: 12_000_000 };
+
+        let (brg, osr, error) = usart_divider(u_pclk, 115_200);
+
+        assert_eq!(brg, 7);
+        assert_eq!(osr, 13);
+        assert_eq!(error.actual, 115_384);
+        assert_eq!(error.ppm, 1597);
+    }
+
+    #[test]
+    fn uartfrg_config_should_minimize_error() {
+        let (clkdiv, frgmult, u_pclk) =
+            uartfrg_config(12_000_000, &[115_200, 9600]);
+
+        assert_eq!(clkdiv, 2);
+        assert_eq!(frgmult, 47);
+        assert_eq!(u_pclk.hz, 12_000_000 * 256 / (2 * (256 + 47)));
+
+        for &baud_rate in [115_200, 9600].iter() {
+            let (_, _, error) = usart_divider(u_pclk, baud_rate);
+            assert!(error.ppm.abs() < 100);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn uartfrg_config_should_reject_too_high_baud_rate() {
+        uartfrg_config(12_000_000, &[3_000_000]);
+    }
+}
@@ -10,23 +10,16 @@
 //! ``` no_run
 //! use lpc82x_hal::prelude::*;
 //! use lpc82x_hal::Peripherals;
-//! use lpc82x_hal::usart::{
-//!     BaudRate,
-//!     USART,
-//! };
+//! use lpc82x_hal::syscon::clocksource::UsartClock;
 //!
 //! let mut p = Peripherals::take().unwrap();
 //!
 //! let mut syscon = p.SYSCON.split();
 //! let mut swm    = p.SWM.split();
 //!
-//! // Set baud rate to 115200 baud
-//! // Please refer to the USART example in the repository for a full
-//! // explanation of this value.
-//! syscon.uartfrg.set_clkdiv(6);
-//! syscon.uartfrg.set_frgmult(22);
-//! syscon.uartfrg.set_frgdiv(0xff);
-//! let baud_rate = BaudRate::new(&syscon.uartfrg, 0);
+//! // Set baud rate to 115200 baud, assuming the main clock runs at 12 MHz
+//! let u_pclk = syscon.uartfrg.set_baud_rates(12_000_000, &[115200]);
+//! let (clock_config, _) = UsartClock::from_u_pclk(&u_pclk, 115200);
 //!
 //! let (u0_rxd, _) = swm.movable_functions.u0_rxd.assign(
 //!     swm.pins.pio0_0.into_swm_pin(),
@@ -41,7 +34,7 @@
 //! // returns a `Result::Err` is when the transmitter is busy, which it
 //! // shouldn't be right now.
 //! let mut serial = p.USART0.enable(
-//!     &baud_rate,
+//!     &clock_config,
 //!     &mut syscon.handle,
 //!     u0_rxd,
 //!     u0_txd,
@@ -57,7 +50,14 @@
 
 use core::fmt;
 use core::ops::Deref;
+#[cfg(feature = "845")]
+use core::{
+    marker::PhantomData,
+    mem,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
+use cortex_m::interrupt;
 use embedded_hal::blocking::serial::write::Default as BlockingWriteDefault;
 use embedded_hal::serial::{Read, Write};
 use nb::{self, block};
@@ -65,7 +65,12 @@ use void::Void;
 
 use crate::{
     dma, init_state,
-    pac::{self, usart0::TXDAT, Interrupt, NVIC},
+    pac::{
+        self,
+        usart0::{RXDAT, TXDAT},
+        Interrupt, NVIC, SCB,
+    },
+    pmu,
     swm::{self, FunctionTrait, PinTrait},
     syscon::{self, clocksource::UsartClock, PeripheralClock},
 };
@@ -172,9 +177,77 @@ where
             _state: init_state::Enabled(()),
         }
     }
+
+    /// Enable the USART in synchronous slave mode
+    ///
+    /// This method is only available, if `USART` is in the [`Disabled`] state.
+    /// Code that attempts to call this method when the peripheral is already
+    /// enabled will not compile.
+    ///
+    /// In synchronous slave mode, the USART is clocked by the SCLK signal
+    /// provided by the other side, which means no baud rate needs to be
+    /// configured. Data is sampled on the falling edge of SCLK.
+    ///
+    /// As the USART doesn't need U_PCLK to receive in this mode, it can receive
+    /// data while the system is in deep-sleep mode. See
+    /// [`USART::receive_in_deep_sleep`].
+    ///
+    /// Consumes this instance of `USART` and returns another instance that has
+    /// its `State` type parameter set to [`Enabled`], with [`SyncSlave`] as
+    /// its payload.
+    ///
+    /// [`Disabled`]: ../init_state/struct.Disabled.html
+    /// [`Enabled`]: ../init_state/struct.Enabled.html
+    /// [`USART::receive_in_deep_sleep`]: #method.receive_in_deep_sleep
+    /// [`SyncSlave`]: struct.SyncSlave.html
+    pub fn enable_sync_slave<RxPin, TxPin, SclkPin>(
+        self,
+        syscon: &mut syscon::Handle,
+        _: swm::Function<I::Rx, swm::state::Assigned<RxPin>>,
+        _: swm::Function<I::Tx, swm::state::Assigned<TxPin>>,
+        _: swm::Function<I::Sclk, swm::state::Assigned<SclkPin>>,
+    ) -> USART<I, init_state::Enabled<SyncSlave>>
+    where
+        RxPin: PinTrait,
+        TxPin: PinTrait,
+        SclkPin: PinTrait,
+        I::Rx: FunctionTrait<RxPin>,
+        I::Tx: FunctionTrait<TxPin>,
+        I::Sclk: FunctionTrait<SclkPin>,
+    {
+        syscon.enable_clock(&self.usart);
+
+        // See comment in `enable` regarding the requirements for writing CFG.
+        self.usart.cfg.modify(|_, w| {
+            w.enable().enabled();
+            w.datalen().bit_8();
+            w.paritysel().no_parity();
+            w.stoplen().bit_1();
+            w.ctsen().disabled();
+            w.syncen().synchronous_mode();
+            w.clkpol().falling_edge();
+            w.syncmst().slave();
+            w.loop_().normal();
+            w.autoaddr().disabled();
+            w.rxpol().standard();
+            w.txpol().standard()
+        });
+
+        self.usart.ctl.modify(|_, w| {
+            w.txbrken().normal();
+            w.addrdet().disabled();
+            w.txdis().enabled();
+            w.autobaud().disabled()
+        });
+
+        USART {
+            usart: self.usart,
+            _state: init_state::Enabled(SyncSlave),
+        }
+    }
 }
 
-impl<I> USART<I, init_state::Enabled>
+impl<I, Mode> USART<I, init_state::Enabled<Mode>>
 where
     I: Instance,
 {
@@ -212,14 +285,202 @@ where
     }
 
     /// Return USART receiver
-    pub fn rx(&self) -> Rx<I> {
+    pub fn rx(&self) -> Rx<I, Mode> {
         Rx(self)
     }
 
     /// Return USART transmitter
-    pub fn tx(&self) -> Tx<I> {
+    pub fn tx(&self) -> Tx<I, Mode> {
         Tx(self)
     }
+
+    /// Enable interrupt sources
+    ///
+    /// Enables all interrupt sources that are set in `interrupts`. Interrupt
+    /// sources that are not set are left unchanged.
+    ///
+    /// The interrupts will not actually work unless the interrupts for this
+    /// peripheral have also been enabled via the NVIC. See
+    /// [`enable_interrupts`].
+    ///
+    /// [`enable_interrupts`]: #method.enable_interrupts
+    pub fn enable_interrupt_sources(&mut self, interrupts: Interrupts) {
+        // Sound, as only the bits defined in `Interrupts` can be set.
+        self.usart
+            .intenset
+            .write(|w| unsafe { w.bits(interrupts.to_bits()) });
+    }
+
+    /// Disable interrupt sources
+    ///
+    /// Disables all interrupt sources that are set in `interrupts`. Interrupt
+    /// sources that are not set are left unchanged.
+    pub fn disable_interrupt_sources(&mut self, interrupts: Interrupts) {
+        // Sound, as only the bits defined in `Interrupts` can be set.
+        self.usart
+            .intenclr
+            .write(|w| unsafe { w.bits(interrupts.to_bits()) });
+    }
+
+    /// Return the pending interrupts
+    ///
+    /// Only interrupts whose source is enabled are returned. The status flags
+    /// are not cleared. See [`take_pending_interrupts`].
+    ///
+    /// [`take_pending_interrupts`]: #method.take_pending_interrupts
+    pub fn pending_interrupts(&self) -> Interrupts {
+        Interrupts::from_bits(self.usart.intstat.read().bits())
+    }
+
+    /// Return the pending interrupts and clear their status flags
+    ///
+    /// This is intended to be called from the interrupt handler. Only
+    /// interrupts whose source is enabled are returned.
+    ///
+    /// The status flags of the following interrupts can't be cleared by this
+    /// method, as they reflect the current state of the USART: `rxrdy`
+    /// (cleared by reading the received character), `txrdy` and `txidle`
+    /// (cleared by writing a character), and `txdis` (cleared by enabling the
+    /// transmitter). Those interrupts stay pending until their condition goes
+    /// away, or until they are disabled.
+    ///
+    /// See user manual, section 13.6.3.
+    pub fn take_pending_interrupts(&mut self) -> Interrupts {
+        let pending = self.pending_interrupts();
+
+        // Sound, as only the bits defined in `Interrupts` can be set.
+        self.usart.stat.write(|w| unsafe {
+            w.bits(pending.to_bits() & Interrupts::CLEARABLE)
+        });
+
+        pending
+    }
+}
+
+impl<I> USART<I, init_state::Enabled<SyncSlave>>
+where
+    I: WakeUpInstance,
+{
+    /// Sleep in deep-sleep mode until a character has been received
+    ///
+    /// This method is only available, if the USART is in synchronous slave
+    /// mode (see [`USART::enable_sync_slave`]). In this mode, the USART is
+    /// clocked by the other side and can receive a full character while the
+    /// system is in deep-sleep mode. The RXRDY interrupt then wakes up the
+    /// system, and the received character is returned. No data is lost in the
+    /// process.
+    ///
+    /// This method takes care of all necessary configuration:
+    /// - It enables this USART as a wake-up source in STARTERP1 for the
+    ///   duration of the call. The previous setting is restored afterwards.
+    /// - It copies the current contents of PDRUNCFG into PDAWAKECFG, so all
+    ///   analog blocks (including the clocks) that are currently powered up
+    ///   are powered up again after waking up.
+    /// - It selects the IRC/FRO as the main clock for the duration of the
+    ///   call, as required by the user manual, section 6.7.5.2. The previous
+    ///   main clock is selected again afterwards. If that is the system PLL,
+    ///   this method waits for it to lock first.
+    /// - It enables the RXRDY interrupt and unmasks the USART interrupt in the
+    ///   NVIC for the duration of the call. Both are restored afterwards, and
+    ///   the USART interrupt handler won't run for the received character.
+    ///
+    /// In asynchronous mode, the USART needs U_PCLK to detect the start bit,
+    /// which is not available in deep-sleep mode. This is why the first
+    /// character would get lost, and why this method is not available in that
+    /// mode.
+    ///
+    /// # Example
+    ///
+    /// ``` no_run
+    /// use lpc82x_hal::Peripherals;
+    ///
+    /// let mut p = Peripherals::take().unwrap();
+    ///
+    /// let mut pmu    = p.PMU.split();
+    /// let mut swm    = p.SWM.split();
+    /// let mut syscon = p.SYSCON.split();
+    ///
+    /// let (u0_rxd, _) = swm.movable_functions.u0_rxd.assign(
+    ///     swm.pins.pio0_0.into_swm_pin(),
+    ///     &mut swm.handle,
+    /// );
+    /// let (u0_txd, _) = swm.movable_functions.u0_txd.assign(
+    ///     swm.pins.pio0_4.into_swm_pin(),
+    ///     &mut swm.handle,
+    /// );
+    /// let (u0_sclk, _) = swm.movable_functions.u0_sclk.assign(
+    ///     swm.pins.pio0_12.into_swm_pin(),
+    ///     &mut swm.handle,
+    /// );
+    ///
+    /// let mut serial = p.USART0.enable_sync_slave(
+    ///     &mut syscon.handle,
+    ///     u0_rxd,
+    ///     u0_txd,
+    ///     u0_sclk,
+    /// );
+    ///
+    /// let received = serial.receive_in_deep_sleep(
+    ///     &mut pmu.handle,
+    ///     &mut syscon.handle,
+    ///     &mut p.SCB,
+    /// );
+    /// ```
+    ///
+    /// [`USART::enable_sync_slave`]: #method.enable_sync_slave
+    pub fn receive_in_deep_sleep(
+        &mut self,
+        pmu: &mut pmu::Handle,
+        syscon: &mut syscon::Handle,
+        scb: &mut SCB,
+    ) -> Result<u8, Error> {
+        let wakeup_enabled = syscon.is_interrupt_wakeup_enabled::<I::WakeUp>();
+        syscon.enable_interrupt_wakeup::<I::WakeUp>();
+        let main_clock = syscon.select_irc_main_clock();
+        syscon.retain_power_config_on_wakeup();
+
+        let rxrdy_enabled = self.usart.intenset.read().rxrdyen().bit_is_set();
+        self.usart.intenset.write(|w| w.rxrdyen().set_bit());
+
+        let result = interrupt::free(|_| {
+            let nvic_enabled = NVIC::is_enabled(I::INTERRUPT);
+
+            // Safe, as we're in a critical section. The interrupt will only
+            // wake up the system, but the handler won't run.
+            unsafe { NVIC::unmask(I::INTERRUPT) };
+
+            let result = loop {
+                match self.rx().read() {
+                    Ok(word) => break Ok(word),
+                    Err(nb::Error::Other(error)) => break Err(error),
+                    Err(nb::Error::WouldBlock) => {
+                        // Safe, as the IRC/FRO is the main clock, and
+                        // PDAWAKECFG has been set up to match the current
+                        // state of PDRUNCFG. All peripherals will be in the
+                        // same state after waking up.
+                        unsafe { pmu.enter_deep_sleep_mode(scb) };
+                    }
+                }
+            };
+
+            if !nvic_enabled {
+                NVIC::mask(I::INTERRUPT);
+            }
+            NVIC::unpend(I::INTERRUPT);
+
+            result
+        });
+
+        if !rxrdy_enabled {
+            self.usart.intenclr.write(|w| w.rxrdyclr().set_bit());
+        }
+        syscon.restore_main_clock(main_clock);
+        if !wakeup_enabled {
+            syscon.disable_interrupt_wakeup::<I::WakeUp>();
+        }
+
+        result
+    }
 }
 
 impl<I, State> USART<I, State> {
@@ -241,9 +502,11 @@ impl<I, State> USART<I, State> {
 }
 
 /// USART receiver
-pub struct Rx<'usart, I: 'usart>(&'usart USART<I>);
+pub struct Rx<'usart, I: 'usart, Mode: 'usart = ()>(
+    &'usart USART<I, init_state::Enabled<Mode>>,
+);
 
-impl<'usart, I> Rx<'usart, I>
+impl<'usart, I, Mode> Rx<'usart, I, Mode>
 where
     I: Instance,
 {
@@ -264,7 +527,7 @@ where
     }
 }
 
-impl<'usart, I> Read<u8> for Rx<'usart, I>
+impl<'usart, I, Mode> Read<u8> for Rx<'usart, I, Mode>
 where
     I: Instance,
 {
@@ -302,10 +565,75 @@ where
     }
 }
 
+impl<'usart, I, Mode> dma::Endpoint for Rx<'usart, I, Mode>
+where
+    I: Instance,
+{
+    type Error = Error;
+
+    fn wait(&mut self) -> nb::Result<(), Self::Error> {
+        // The DMA controller reads RXDAT, which doesn't carry any error flags.
+        // All we can do is check the error flags in the status register.
+        let stat = self.0.usart.stat.read();
+
+        // Bit positions of the error flags in STAT, as in `Interrupts`
+        let (error, bit) = if stat.overrunint().bit_is_set() {
+            (Error::Overrun, 8)
+        } else if stat.framerrint().bit_is_set() {
+            (Error::Framing, 13)
+        } else if stat.parityerrint().bit_is_set() {
+            (Error::Parity, 14)
+        } else if stat.rxnoiseint().bit_is_set() {
+            (Error::Noise, 15)
+        } else {
+            return Ok(());
+        };
+
+        // The error flags are sticky. Clear the one we report, so it doesn't
+        // affect later transfers.
+        //
+        // Sound, as the flag is cleared by writing `1`, and no other bits are
+        // written.
+        self.0.usart.stat.write(|w| unsafe { w.bits(0x1 << bit) });
+
+        Err(nb::Error::Other(error))
+    }
+}
+
+impl<'usart, I, Mode> dma::Source for Rx<'usart, I, Mode>
+where
+    I: Instance,
+{
+    type Word = u8;
+
+    fn is_peripheral(&self) -> bool {
+        true
+    }
+
+    fn increment(&self) -> dma::Increment {
+        dma::Increment::None
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn start_addr(&mut self) -> *const Self::Word {
+        &self.0.usart.rxdat as *const _ as *const RXDAT as *const u8
+    }
+}
+
+impl<'usart, I, Mode> dma::CompatibleWith<I::RxChannel> for Rx<'usart, I, Mode> where
+    I: Instance
+{
+}
+
 /// USART transmitter
-pub struct Tx<'usart, I: 'usart>(&'usart USART<I>);
+pub struct Tx<'usart, I: 'usart, Mode: 'usart = ()>(
+    &'usart USART<I, init_state::Enabled<Mode>>,
+);
 
-impl<'usart, I> Tx<'usart, I>
+impl<'usart, I, Mode> Tx<'usart, I, Mode>
 where
     I: Instance,
 {
@@ -326,7 +654,7 @@ where
     }
 }
 
-impl<'usart, I> Write<u8> for Tx<'usart, I>
+impl<'usart, I, Mode> Write<u8> for Tx<'usart, I, Mode>
 where
     I: Instance,
 {
@@ -353,9 +681,12 @@ where
     }
 }
 
-impl<'usart, I> BlockingWriteDefault<u8> for Tx<'usart, I> where I: Instance {}
+impl<'usart, I, Mode> BlockingWriteDefault<u8> for Tx<'usart, I, Mode> where
+    I: Instance
+{
+}
 
-impl<'usart, I> fmt::Write for Tx<'usart, I>
+impl<'usart, I, Mode> fmt::Write for Tx<'usart, I, Mode>
 where
     Self: BlockingWriteDefault<u8>,
     I: Instance,
@@ -370,7 +701,7 @@ where
     }
 }
 
-impl<'usart, I> dma::Dest for Tx<'usart, I>
+impl<'usart, I, Mode> dma::Endpoint for Tx<'usart, I, Mode>
 where
     I: Instance,
 {
@@ -379,12 +710,36 @@ where
     fn wait(&mut self) -> nb::Result<(), Self::Error> {
         self.flush()
     }
+}
+
+impl<'usart, I, Mode> dma::Dest for Tx<'usart, I, Mode>
+where
+    I: Instance,
+{
+    type Word = u8;
+
+    fn is_peripheral(&self) -> bool {
+        true
+    }
+
+    fn increment(&self) -> dma::Increment {
+        dma::Increment::None
+    }
+
+    fn transfer_count(&self) -> Option<usize> {
+        None
+    }
 
-    fn end_addr(&mut self) -> *mut u8 {
+    fn start_addr(&mut self) -> *mut Self::Word {
         &self.0.usart.txdat as *const _ as *mut TXDAT as *mut u8
     }
 }
 
+impl<'usart, I, Mode> dma::CompatibleWith<I::TxChannel> for Tx<'usart, I, Mode> where
+    I: Instance
+{
+}
+
 /// Internal trait for USART peripherals
 ///
 /// This trait is an internal implementation detail and should neither be
@@ -403,6 +758,25 @@ pub trait Instance:
 
     /// The movable function that needs to be assigned to this USART's TX pin
     type Tx;
+
+    /// The movable function that needs to be assigned to this USART's SCLK pin
+    type Sclk;
+
+    /// The DMA channel that is hard-wired to this USART's RX request
+    type RxChannel: dma::ChannelTrait;
+
+    /// The DMA channel that is hard-wired to this USART's TX request
+    type TxChannel: dma::ChannelTrait;
+}
+
+/// Internal trait for USART peripherals that can wake up the system
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+pub trait WakeUpInstance: Instance {
+    /// The type that enables interrupt wake-up for this USART
+    type WakeUp: syscon::WakeUpInterrupt;
 }
 
 macro_rules! instances {
@@ -411,32 +785,320 @@ macro_rules! instances {
             $instance:ident,
             $interrupt:ident,
             $rx:ident,
-            $tx:ident;
+            $tx:ident,
+            $sclk:ident,
+            $rx_channel:ident,
+            $tx_channel:ident;
         )*
     ) => {
         $(
             impl Instance for pac::$instance {
                 const INTERRUPT: Interrupt = Interrupt::$interrupt;
 
-                type Rx = swm::$rx;
-                type Tx = swm::$tx;
+                type Rx   = swm::$rx;
+                type Tx   = swm::$tx;
+                type Sclk = swm::$sclk;
+
+                type RxChannel = dma::$rx_channel;
+                type TxChannel = dma::$tx_channel;
             }
         )*
     };
 }
 
 instances!(
-    USART0, USART0, U0_RXD, U0_TXD;
-    USART1, USART1, U1_RXD, U1_TXD;
-    USART2, USART2, U2_RXD, U2_TXD;
+    USART0, USART0, U0_RXD, U0_TXD, U0_SCLK, Channel0, Channel1;
+    USART1, USART1, U1_RXD, U1_TXD, U1_SCLK, Channel2, Channel3;
+    USART2, USART2, U2_RXD, U2_TXD, U2_SCLK, Channel4, Channel5;
 );
 
 #[cfg(feature = "845")]
 instances!(
-    USART3, PIN_INT6_USART3, U3_RXD, U3_TXD;
-    USART4, PIN_INT7_USART4, U4_RXD, U4_TXD;
+    USART3, PIN_INT6_USART3, U3_RXD, U3_TXD, U3_SCLK, Channel6, Channel7;
+    USART4, PIN_INT7_USART4, U4_RXD, U4_TXD, U4_SCLK, Channel8, Channel9;
 );
 
+impl WakeUpInstance for pac::USART0 {
+    type WakeUp = syscon::Usart0Wakeup;
+}
+impl WakeUpInstance for pac::USART1 {
+    type WakeUp = syscon::Usart1Wakeup;
+}
+impl WakeUpInstance for pac::USART2 {
+    type WakeUp = syscon::Usart2Wakeup;
+}
+
+/// Internal trait for USART peripherals that share an interrupt
+///
+/// On LPC845, USART3 and USART4 share their interrupt vectors with pin
+/// interrupts 6 and 7 respectively. See [`SharedInterrupt`].
+///
+/// This trait is an internal implementation detail and should neither be
+/// implemented nor used outside of LPC8xx HAL. Any changes to this trait won't
+/// be considered breaking changes.
+///
+/// [`SharedInterrupt`]: struct.SharedInterrupt.html
+#[cfg(feature = "845")]
+pub trait SharedInterruptInstance: Instance {
+    /// The pin interrupt that shares the interrupt vector with this USART
+    const PIN_INTERRUPT: u8;
+
+    /// Return a pointer to the USART's registers
+    fn ptr() -> *const pac::usart0::RegisterBlock;
+}
+
+#[cfg(feature = "845")]
+impl SharedInterruptInstance for pac::USART3 {
+    const PIN_INTERRUPT: u8 = 6;
+
+    fn ptr() -> *const pac::usart0::RegisterBlock {
+        pac::USART3::ptr()
+    }
+}
+
+#[cfg(feature = "845")]
+impl SharedInterruptInstance for pac::USART4 {
+    const PIN_INTERRUPT: u8 = 7;
+
+    fn ptr() -> *const pac::usart0::RegisterBlock {
+        pac::USART4::ptr()
+    }
+}
+
+/// Dispatches an interrupt that is shared between a USART and a pin interrupt
+///
+/// On LPC845, USART3 shares its interrupt vector with pin interrupt 6
+/// (`PIN_INT6_USART3`), and USART4 shares its interrupt vector with pin
+/// interrupt 7 (`PIN_INT7_USART4`). This dispatcher allows both the USART and
+/// the pin interrupt to be used at the same time. A handler can be registered
+/// for each of them, and [`SharedInterrupt::dispatch`] calls each handler
+/// whose peripheral has an interrupt pending.
+///
+/// # Example
+///
+/// ``` no_run
+/// use lpc8xx_hal::{pac, usart::SharedInterrupt};
+///
+/// static USART3_SHARED: SharedInterrupt<pac::USART3> =
+///     SharedInterrupt::new();
+///
+/// fn handle_usart3() {
+///     // handle USART3 interrupt
+/// }
+///
+/// fn handle_pin_int6() {
+///     // handle pin interrupt 6
+/// }
+///
+/// USART3_SHARED.register_usart(handle_usart3);
+/// USART3_SHARED.register_pin_interrupt(handle_pin_int6);
+///
+/// // This needs to be called from the `PIN_INT6_USART3` interrupt handler.
+/// USART3_SHARED.dispatch();
+/// ```
+///
+/// [`SharedInterrupt::dispatch`]: #method.dispatch
+#[cfg(feature = "845")]
+pub struct SharedInterrupt<I> {
+    usart: AtomicUsize,
+    pin_interrupt: AtomicUsize,
+    _instance: PhantomData<fn() -> I>,
+}
+
+#[cfg(feature = "845")]
+impl<I> SharedInterrupt<I> {
+    /// Create a new dispatcher with no handlers registered
+    pub const fn new() -> Self {
+        SharedInterrupt {
+            usart: AtomicUsize::new(0),
+            pin_interrupt: AtomicUsize::new(0),
+            _instance: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "845")]
+impl<I> SharedInterrupt<I>
+where
+    I: SharedInterruptInstance,
+{
+    /// Register the handler for the USART interrupt
+    ///
+    /// Replaces any previously registered USART handler.
+    pub fn register_usart(&self, handler: fn()) {
+        self.usart.store(handler as usize, Ordering::SeqCst);
+    }
+
+    /// Register the handler for the pin interrupt
+    ///
+    /// Replaces any previously registered pin interrupt handler.
+    pub fn register_pin_interrupt(&self, handler: fn()) {
+        self.pin_interrupt.store(handler as usize, Ordering::SeqCst);
+    }
+
+    /// Remove the handler for the USART interrupt
+    pub fn unregister_usart(&self) {
+        self.usart.store(0, Ordering::SeqCst);
+    }
+
+    /// Remove the handler for the pin interrupt
+    pub fn unregister_pin_interrupt(&self) {
+        self.pin_interrupt.store(0, Ordering::SeqCst);
+    }
+
+    /// Dispatch the shared interrupt
+    ///
+    /// This method needs to be called from the interrupt handler of the shared
+    /// interrupt. It checks the pending flags of both the USART (INTSTAT) and
+    /// the pin interrupt (IST in PINT), and calls the registered handler for
+    /// each peripheral that has an interrupt pending.
+    ///
+    /// The handlers are responsible for clearing the interrupt condition of
+    /// their respective peripheral.
+    pub fn dispatch(&self) {
+        // Sound, as reading these registers has no side effects, and the
+        // pointers are valid for the duration of the program.
+        let usart = unsafe { &*I::ptr() };
+        let pint = unsafe { &*pac::PINT::ptr() };
+
+        let usart_pending = usart.intstat.read().bits() != 0;
+        let pin_interrupt_pending =
+            pint.ist.read().bits() & (0x1 << I::PIN_INTERRUPT) != 0;
+
+        if usart_pending {
+            call(&self.usart);
+        }
+        if pin_interrupt_pending {
+            call(&self.pin_interrupt);
+        }
+    }
+}
+
+#[cfg(feature = "845")]
+fn call(handler: &AtomicUsize) {
+    let handler = handler.load(Ordering::SeqCst);
+    if handler != 0 {
+        // Sound, as only values created from `fn()` are ever stored in the
+        // handler fields, apart from 0, which we've checked for.
+        let handler: fn() = unsafe { mem::transmute(handler) };
+        handler();
+    }
+}
+
+/// Indicates that a USART is enabled in synchronous slave mode
+///
+/// Used as the payload of [`init_state::Enabled`] in the `State` type
+/// parameter of [`USART`]. See [`USART::enable_sync_slave`].
+///
+/// [`init_state::Enabled`]: ../init_state/struct.Enabled.html
+/// [`USART`]: struct.USART.html
+/// [`USART::enable_sync_slave`]: struct.USART.html#method.enable_sync_slave
+pub struct SyncSlave;
+
+/// A set of USART interrupts
+///
+/// Each field corresponds to one of the interrupt sources in INTENSET. Can be
+/// used to enable or disable interrupt sources, and is returned when checking
+/// for pending interrupts. See [`USART::enable_interrupt_sources`] and
+/// [`USART::take_pending_interrupts`].
+///
+/// See user manual, section 13.6.5.
+///
+/// [`USART::enable_interrupt_sources`]: struct.USART.html#method.enable_interrupt_sources
+/// [`USART::take_pending_interrupts`]: struct.USART.html#method.take_pending_interrupts
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Interrupts {
+    /// The receiver has data available
+    pub rxrdy: bool,
+
+    /// The transmitter is ready for more data
+    pub txrdy: bool,
+
+    /// The transmitter is idle
+    pub txidle: bool,
+
+    /// A change of the CTS input has been detected
+    pub deltacts: bool,
+
+    /// The transmitter has been fully disabled
+    pub txdis: bool,
+
+    /// An overrun has occured
+    pub overrun: bool,
+
+    /// A change in the received break condition has been detected
+    pub deltarxbrk: bool,
+
+    /// A start bit has been detected
+    pub start: bool,
+
+    /// A framing error has been detected
+    pub framerr: bool,
+
+    /// A parity error has been detected
+    pub parityerr: bool,
+
+    /// Noise has been detected in a received character
+    pub rxnoise: bool,
+
+    /// An auto-baud error has occured
+    pub aberr: bool,
+}
+
+impl Interrupts {
+    /// The status flags that can be cleared by writing to STAT
+    const CLEARABLE: u32 = 0x1f920;
+
+    fn to_bits(self) -> u32 {
+        let mut bits = 0;
+
+        for &(flag, bit) in &self.flags() {
+            if flag {
+                bits |= 0x1 << bit;
+            }
+        }
+
+        bits
+    }
+
+    fn from_bits(bits: u32) -> Self {
+        let is_set = |bit: u32| bits & (0x1 << bit) != 0;
+
+        Self {
+            rxrdy: is_set(0),
+            txrdy: is_set(2),
+            txidle: is_set(3),
+            deltacts: is_set(5),
+            txdis: is_set(6),
+            overrun: is_set(8),
+            deltarxbrk: is_set(11),
+            start: is_set(12),
+            framerr: is_set(13),
+            parityerr: is_set(14),
+            rxnoise: is_set(15),
+            aberr: is_set(16),
+        }
+    }
+
+    /// Returns each flag, together with its bit position in the registers
+    fn flags(&self) -> [(bool, u32); 12] {
+        [
+            (self.rxrdy, 0),
+            (self.txrdy, 2),
+            (self.txidle, 3),
+            (self.deltacts, 5),
+            (self.txdis, 6),
+            (self.overrun, 8),
+            (self.deltarxbrk, 11),
+            (self.start, 12),
+            (self.framerr, 13),
+            (self.parityerr, 14),
+            (self.rxnoise, 15),
+            (self.aberr, 16),
+        ]
+    }
+}
+
 /// A USART error
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Error {
@@ -452,3 +1114,42 @@ pub enum Error {
     /// Parity error detected in received character
     Parity,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Interrupts;
+
+    #[test]
+    fn interrupts_should_round_trip_through_bits() {
+        let all = 0x1f96d;
+
+        assert_eq!(Interrupts::from_bits(all).to_bits(), all);
+
+        for bit in 0..32 {
+            let bits = (0x1 << bit) & all;
+            assert_eq!(Interrupts::from_bits(bits).to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn interrupts_should_ignore_reserved_bits() {
+        assert_eq!(Interrupts::from_bits(!0x1f96d), Interrupts::default());
+    }
+
+    #[test]
+    fn clearable_should_contain_write_1_to_clear_flags() {
+        let clearable = Interrupts {
+            deltacts: true,
+            overrun: true,
+            deltarxbrk: true,
+            start: true,
+            framerr: true,
+            parityerr: true,
+            rxnoise: true,
+            aberr: true,
+            ..Interrupts::default()
+        };
+
+        assert_eq!(clearable.to_bits(), Interrupts::CLEARABLE);
+    }
+}
@@ -0,0 +1,15 @@
+extern crate lpc8xx_hal;
+
+
+use lpc8xx_hal::Peripherals;
+
+
+fn main() {
+    let p = Peripherals::take().unwrap();
+
+    let swm = p.SWM.split();
+
+    // None of the 33-pin packages have PIO0_29
+    let _ = swm.pins.pio0_29;
+    //~^ ERROR no field `pio0_29`
+}
@@ -0,0 +1,30 @@
+extern crate lpc8xx_hal;
+
+
+use lpc8xx_hal::{
+    dma,
+    init_state,
+    pac,
+    usart,
+};
+
+
+static mut BUFFER: [u8; 4] = [0; 4];
+
+
+// Takes the channel and the USART as arguments, as creating them differs
+// between LPC82x and LPC845.
+fn start_transfer<'dma>(
+    channel: dma::Channel<
+        dma::Channel0,
+        init_state::Enabled<&'dma dma::Handle>,
+    >,
+    serial: &usart::USART<pac::USART0>,
+) {
+    // USART0 TX is hard-wired to channel 1, not channel 0
+    let _ = channel.start_transfer(unsafe { &BUFFER[..] }, serial.tx());
+    //~^ ERROR the trait bound
+}
+
+
+fn main() {}
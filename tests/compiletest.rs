@@ -6,14 +6,21 @@ use std::path::PathBuf;
 
 #[test]
 fn compile_test() {
-    run_mode("compile-fail");
+    run_mode("compile-fail", "compile-fail");
 }
 
-fn run_mode(mode: &'static str) {
+// Tests that only fail, if a package with 33 pins is selected
+#[test]
+#[cfg(feature = "33")]
+fn compile_test_33() {
+    run_mode("compile-fail", "compile-fail-33");
+}
+
+fn run_mode(mode: &'static str, dir: &'static str) {
     let mut config = compiletest::Config::default();
 
     config.mode = mode.parse().expect("Failed to parse mode");
-    config.src_base = PathBuf::from(format!("tests/{}", mode));
+    config.src_base = PathBuf::from(format!("tests/{}", dir));
 
     // Needed by the compiler to find other crates
     config.link_deps();